@@ -3,13 +3,44 @@
 /// **Author: @_Yunhao Xu_**
 ///
 /// **Version: v1.0.0**
-
 pub mod fileapi {
-    use std::fmt::Debug;
-    use std::fs::{File, remove_file};
-    use std::io::{Read, Write};
-    use std::path::Path;
+    use std::cmp::Ordering;
+    use std::collections::{HashMap, HashSet};
+    use std::error::Error;
+    use std::fmt::{self, Debug, Display, Formatter};
+    use std::fs::{File, OpenOptions, copy, remove_file, rename};
+    use std::hash::Hash;
+    use std::iter::Sum;
+    use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+    use std::marker::PhantomData;
+    use std::ops::{Add, RangeInclusive};
+    use std::path::{Path, PathBuf};
     use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+    use std::time::SystemTime;
+
+    #[cfg(feature = "encoding_rs")]
+    pub use encoding_rs;
+    #[cfg(feature = "encoding_rs")]
+    use encoding_rs::Encoding;
+    #[cfg(feature = "memmap")]
+    use memmap2::Mmap;
+    #[cfg(feature = "memmap")]
+    use std::cell::OnceCell;
+    #[cfg(feature = "rayon")]
+    use rayon::prelude::*;
+    #[cfg(feature = "regex")]
+    use regex::Regex;
+    #[cfg(feature = "serde")]
+    use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, SeqAccess, Visitor};
+    #[cfg(feature = "serde")]
+    use serde::ser::{self, Impossible, Serialize, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct, Serializer};
+
+    // the byte order mark, which some tools write at the start of a UTF-8 file.
+    const BOM: char = '\u{feff}';
+
+    // the number of the temporary files created by the atomic writes in this process.
+    static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
     /// A structure of file modified API. This class is used to change, read, write, remove a file in the project.
     ///
@@ -32,21 +63,38 @@ pub mod fileapi {
     ///
     /// // custom the split character.
     /// let file = FileAPI::from("filename.gph").split(',');
-    /// let reader = file.reader();
+    /// let reader = file.reader()?;
     ///
     /// // read the header of the file
-    /// let header = reader.read_header::<usize>(1)[0].clone();
+    /// let header = reader.read_header_line::<usize>()?;
     ///
     /// assert_eq!(header, vec![1, 2, 3]);
-    ///
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
     /// ```
     /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
     ///
+    /// With the `serde` feature, the [FileAPI] can be serialized and deserialized with all its
+    /// settings, such as to keep them in a configuration file. The deserialized settings are checked
+    /// like in the functions setting them, and an invalid one gives an error instead of a panic.
+    ///
     /// [split]: FileAPI::split
     /// [from]: FileAPI::from
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(try_from = "FileAPISettings"))]
     pub struct FileAPI {
         pub path: String,
-        split: char
+        split: String,
+        csv_mode: bool,
+        trim: bool,
+        atomic: bool,
+        line_ending: Option<LineEnding>,
+        comment: Option<char>,
+        skip_blank_lines: bool,
+        empty_fields: EmptyFieldPolicy,
+        quote: char,
+        widths: Option<Vec<usize>>,
+        #[cfg(feature = "encoding_rs")]
+        encoding: Option<&'static Encoding>
     }
 
     impl FileAPI {
@@ -62,11 +110,43 @@ pub mod fileapi {
         pub fn from(path: &str) -> FileAPI {
             FileAPI {
                 path: path.to_string(),
-                split: ' '
+                split: String::from(" "),
+                csv_mode: false,
+                trim: true,
+                atomic: true,
+                line_ending: None,
+                comment: None,
+                skip_blank_lines: false,
+                empty_fields: EmptyFieldPolicy::Keep,
+                quote: '"',
+                widths: None,
+                #[cfg(feature = "encoding_rs")]
+                encoding: None
+            }
+        }
+
+        /// Get a copy of this [FileAPI] for another file, which keeps all the settings but the path.
+        /// # Example
+        ///
+        /// process the files of the same format with one configuration:
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let config = FileAPI::from("").split(',').trim(false);
+        /// for path in ["a.csv", "b.csv"] {
+        ///     let file = config.with_path(path);
+        ///     let body = file.reader()?.read_body::<usize>(1, 0)?;
+        /// }
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn with_path(&self, path: &str) -> FileAPI {
+            FileAPI {
+                path: path.to_string(),
+                ..self.clone()
             }
         }
 
-        /// Set the split character for all process (except [read_csv]). The default character is ' ' (whitespace).
+        /// Set the split character for all process. The default character is ' ' (whitespace).
         /// # Example
         ///
         /// ```no_run
@@ -74,225 +154,341 @@ pub mod fileapi {
         ///
         /// let file = FileAPI::from("filename.gph").split(',');
         /// // the values will divided by ','.
-        /// let reader = file.reader();
-        /// let body = reader.read_body::<usize>(1, 1);
+        /// let reader = file.reader()?;
+        /// let body = reader.read_body::<usize>(1, 1)?;
         ///
         /// assert_eq!(body, vec![vec![4, 5, 6], vec![7, 8, 9]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
         ///
-        /// [read_csv]: Reader::read_csv
+        /// # Panics
+        ///
+        /// Panics if `split` is the quote character in [csv_mode].
+        ///
+        /// [csv_mode]: FileAPI::csv_mode
         pub fn split(mut self, split: char) -> Self {
-            self.split = split.clone();
+            if self.csv_mode && split == self.quote {
+                panic!("The 'split' parameter should not be the quote character in csv mode.")
+            }
+            self.split = split.to_string();
             self
         }
 
-        /// Get a Reader object for reading several values of the same file in succession.
-        ///
+        /// Set a split string for all process, for the files whose values are
+        /// divided by more than one character, such as `", "` or `" | "`.
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
+        ///
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let reader = file.reader();
+        /// let file = FileAPI::from("filename.gph").split_str(" | ");
+        /// // the values will divided by " | ".
+        /// let reader = file.reader()?;
+        /// let body = reader.read_body::<usize>(1, 1)?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
         ///
-        /// // read the header of the file
-        /// let header = reader.read_header::<usize>(1)[0].clone();
+        /// # Panics
         ///
-        /// assert_eq!(header, vec![1, 2, 3]);
-        /// ```
-        /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
-        pub fn reader(&self) -> Reader {
-            Reader::from(self)
+        /// Panics if `split` is empty, or contains the quote character in [csv_mode].
+        ///
+        /// [csv_mode]: FileAPI::csv_mode
+        pub fn split_str(mut self, split: &str) -> Self {
+            if split.is_empty() {
+                panic!("The 'split' parameter should not be empty.")
+            }
+            if self.csv_mode && split.contains(self.quote) {
+                panic!("The 'split' parameter should not contain the quote character in csv mode.")
+            }
+            self.split = split.to_string();
+            self
         }
 
-        /// Get a Changer object for modifying several values of the same file in succession.
+        /// Read and write the values by their widths in characters instead of a split character, for the
+        /// fixed-width files whose columns are at the same positions in every line. The characters after
+        /// the last column are not read as a value, but they are kept when a [Changer] changes the line.
         ///
+        /// When writing, the values are padded with spaces to their widths, which are removed when
+        /// reading them with trim on, which is the default. A value longer than its width is written as
+        /// it is, so it moves the following columns.
         /// # Example
-        /// collect a [Changer] type (same with [Builder], [Reader]):
-        /// ```no_run
+        ///
+        /// ```
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph").split(',');
-        /// let changer = file.changer();
+        /// let file = FileAPI::from("").fixed_widths(&[7, 3, 4]);
+        /// let reader = file.reader_from("Alice  30 1.75\nBob    7  1.20".as_bytes())?;
         ///
-        /// // change the value in the 2th line and 2th row to value '123':
-        /// // you can consecutive change values:
-        /// changer.change_value(2, 2, "123")
-        ///     .change_value(3, 2, "567")
-        ///     .change_value(4, 2, "560")
-        ///     .execute();  // after modifying the value, you will need to execute your changes.
+        /// assert_eq!(reader.read_line::<String>(1)?, vec!["Alice", "30", "1.75"]);
+        /// assert_eq!(reader.get::<usize>(2, 2)?, 7);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn changer(&self) -> Changer {
-            Changer::from(self)
+        ///
+        /// # Panics
+        ///
+        /// Panics if `widths` is empty or contains 0.
+        pub fn fixed_widths(mut self, widths: &[usize]) -> Self {
+            if widths.is_empty() || widths.contains(&0) {
+                panic!("The 'widths' parameter should not be empty or contain 0.")
+            }
+            self.widths = Some(widths.to_vec());
+            self
         }
 
-        /// Get a Builder object for writing several values for a new file in succession.
-        ///
+        /// Get the split character, or [None] if the split is a string of more than one character set by
+        /// [split_str].
         /// # Example
-        /// collect a [Builder] type (same with [Changer], [Reader]):
-        /// ```no_run
-        /// use self::simple_file_manager::fileapi::FileAPI;
-        ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let mut builder = file.builder();
         ///
-        /// // write a line:
-        /// // you can consecutive write lines:
-        /// builder.write_line("This is the second line.")
-        ///     .write_line("This is the third line.")
-        ///     .write_line("This is the forth line.")
-        ///     .execute(); // you will also need to execute your changes:
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
         ///
+        /// assert_eq!(FileAPI::from("filename.gph").delimiter(), Some(' '));
+        /// assert_eq!(FileAPI::from("filename.gph").split(',').delimiter(), Some(','));
+        /// assert_eq!(FileAPI::from("filename.gph").split_str(", ").delimiter(), None);
         /// ```
-        pub fn builder(&self) -> Builder {
-            Builder::from(self)
+        ///
+        /// [split_str]: FileAPI::split_str
+        pub fn delimiter(&self) -> Option<char> {
+            let mut chars = self.split.chars();
+            chars.next().filter(|_| chars.next().is_none())
         }
 
-        /// A function to remove the file and delete the object.
-        ///
+        /// Get the split string.
         /// # Example
         ///
-        /// ```no_run
+        /// ```
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// FileAPI::from("filename.gph").remove();
+        /// assert_eq!(FileAPI::from("filename.gph").split(',').delimiter_str(), ",");
+        /// assert_eq!(FileAPI::from("filename.gph").split_str(" | ").delimiter_str(), " | ");
         /// ```
-        pub fn remove(&self) {
-            remove_file(self.path.clone()).unwrap();
+        pub fn delimiter_str(&self) -> &str {
+            &self.split
         }
 
-        /// A function to check if the file exist.
+        /// Set the csv mode. In csv mode, a value quoted by '"' may contain the split character or a line
+        /// break, and a '"' inside the quoted value is written as `""`. A line break inside the quotes
+        /// does not end the line, so such a line spans several lines of the file. The default mode is off. The quote character
+        /// can be changed by [quote_char].
         ///
+        /// The quotes are removed when reading, and added back by [Changer] for the values which need
+        /// them.
         /// # Example
         ///
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// if file.is_exist() {
-        ///     file.remove();
-        /// };
+        /// // the file contains a line: "Smith, John",42
+        /// let file = FileAPI::from("people.csv").split(',').csv_mode(true);
+        /// let results = file.reader()?
+        ///     .read_value(1, 1)?
+        ///     .execute::<String>()?;
+        ///
+        /// assert_eq!(results, vec!["Smith, John"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn is_exist(&self) -> bool {
-            Path::new(&self.path).exists()
+        ///
+        ///
+        /// # Panics
+        ///
+        /// Panics if `csv_mode` is true and the quote character is in the split string, as the values
+        /// could not be divided then.
+        ///
+        /// [quote_char]: FileAPI::quote_char
+        pub fn csv_mode(mut self, csv_mode: bool) -> Self {
+            if csv_mode && self.split.contains(self.quote) {
+                panic!("The 'csv_mode' parameter should not be true when the quote character is in the split string.")
+            }
+            self.csv_mode = csv_mode;
+            self
         }
-    }
 
-    impl Clone for FileAPI {
-        fn clone(&self) -> Self {
-            FileAPI {
-                path: self.path.clone(),
-                split: self.split.clone()
+        /// Set the character quoting the values in [csv_mode], such as '\'' or '`' in some dialects.
+        /// The default is '"'.
+        /// # Example
+        ///
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',').csv_mode(true).quote_char('\'');
+        /// let reader = file.reader_from("'Smith, John',42".as_bytes())?;
+        ///
+        /// assert_eq!(reader.read_line::<String>(1)?, vec!["Smith, John", "42"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if `quote` is in the split string in [csv_mode], as the values could not be divided
+        /// then.
+        ///
+        /// [csv_mode]: FileAPI::csv_mode
+        pub fn quote_char(mut self, quote: char) -> Self {
+            if self.csv_mode && self.split.contains(quote) {
+                panic!("The 'quote' parameter should not be in the split string in csv mode.")
             }
+            self.quote = quote;
+            self
         }
-    }
 
-    /// A reader structure for reading specific values of the same file in succession.
-    ///
-    /// # Example
-    /// collect a [Reader] type (same with [Builder], [Changer]):
-    /// ```no_run
-    /// use self::simple_file_manager::fileapi::FileAPI;
-    ///
-    /// let file = FileAPI::from("filename.gph");
-    /// let reader = file.reader();
-    ///
-    /// // read the header of the file
-    /// let header = reader.read_header::<usize>(1)[0].clone();
-    ///
-    /// assert_eq!(header, vec![1, 2, 3]);
-    /// ```
-    /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
-    pub struct Reader<'a> {
-        pub lines: String,
-        file: &'a FileAPI,
-        pub values: Vec<String>
-    }
-
-    impl Reader<'_> {
-        fn from(file: &FileAPI) -> Reader<'_> {
-            let mut the_file = File::open(&file.path).unwrap();
-            let mut lines = String::new();
-            let _ = the_file.read_to_string(&mut lines).unwrap();
-            Reader { lines, file , values: Vec::new()}
+        /// Set whether the leading and trailing whitespace of the values is removed before parsing them.
+        /// The default is true. Turn it off to read the values verbatim, such as fixed-width fields.
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file contains a line: a, b ,c
+        /// let file = FileAPI::from("text.txt").split(',').trim(false);
+        /// let results = file.reader()?
+        ///     .read_value(1, 2)?
+        ///     .execute::<String>()?;
+        ///
+        /// assert_eq!(results, vec![" b "]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn trim(mut self, trim: bool) -> Self {
+            self.trim = trim;
+            self
         }
 
-        /// Read all text in the file.
+        /// Set whether [Changer] and [Builder] write the file atomically. The default is true.
         ///
+        /// In atomic mode, the content is written to a temporary file in the same directory, which is
+        /// renamed to the file only after it is fully written, so the file is never left half written.
+        /// The permissions of an existing file are kept in both modes, and a symbolic link is followed
+        /// to write its target.
+        /// Turn it off to write the file in place, such as when the directory is not writable.
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
+        ///
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let reader = file.reader();
+        /// let file = FileAPI::from("filename.gph").atomic(false);
+        /// file.builder()
+        ///     .write_line("1,2,3")
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn atomic(mut self, atomic: bool) -> Self {
+            self.atomic = atomic;
+            self
+        }
+
+        /// Set the line ending written by [Changer] and [Builder].
         ///
-        /// // read all the lines in the file
-        /// let context = reader.read_to_string();
+        /// By default, [Changer] keeps the line ending found in the file, and [Builder] writes
+        /// [LineEnding::Lf]. All the line endings are recognized when reading the file.
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::{FileAPI, LineEnding};
         ///
-        /// assert_eq!(context, String::from("1,2,3\n4,5,6\n7,8,9\n10,12"))
+        /// // convert the file to windows line endings.
+        /// let file = FileAPI::from("filename.gph").line_ending(LineEnding::CrLf);
+        /// file.changer()?.execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn read_to_string(&self) -> String {
-            self.lines.to_string()
+        pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+            self.line_ending = Some(line_ending);
+            self
         }
 
-        /// A function to read a value in this data storage file.
+        /// Set the character starting the comment lines, such as '#', which are skipped when reading the
+        /// lines as data. A line is a comment if it starts with this character after any whitespace.
         ///
+        /// The comment lines are skipped by [Reader::read_body], [Reader::read_column] and the similar
+        /// functions reading many lines, and by [RowIter] and [ParsedIter]. They are removed before the
+        /// `header` and `footer` lines are counted, so a header of one line is the first line which is not
+        /// a comment. The functions reading a line by its position, such as [Reader::read_line], count
+        /// all the lines including the comments.
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
-        /// ```no_run
-        /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph").split(',');
-        /// let reader = file.reader();
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// // read the value in the 5th line and 7th row :
-        /// // you can consecutive read values:
-        /// let results = reader.read_value(1, 2)
-        ///     .read_value(3, 2)
-        ///     .read_value(2, 1)
-        ///     .execute::<usize>(); // after select the values, you will need to execute and receive the values stored in a [Vec].
+        /// let file = FileAPI::from("").split(',').comment_char('#');
+        /// let reader = file.reader_from("# measured values\nx,y\n1,2\n  # a break\n3,4".as_bytes())?;
         ///
-        /// assert_eq!(results, vec![2, 8, 4]);
+        /// assert_eq!(reader.read_body::<usize>(1, 0)?, vec![vec![1, 2], vec![3, 4]]);
+        /// assert_eq!(reader.read_column::<usize>(2, true)?, vec![2, 4]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn read_value(mut self, line:usize, row:usize) -> Self {
-            let a_line = self.lines
-                .lines()
-                .collect::<Vec<&str>>()[line-1]
-                .split(self.file.split.clone())
-                .collect::<Vec<&str>>();
-            self.values.push(a_line[row - 1].to_string());
+        pub fn comment_char(mut self, comment: char) -> Self {
+            self.comment = Some(comment);
             self
         }
 
-        /// Confirm and receive the selected values.
+        /// Set whether the blank lines, which are empty or only contain whitespace, are skipped when
+        /// reading the lines as data. The default is false, so a blank line is read as a single empty
+        /// value, which cannot be parsed into a number.
         ///
+        /// The blank lines are skipped like the comment lines set by [comment_char], so they are not
+        /// counted in the `header` and `footer` lines either.
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
-        /// ```no_run
+        ///
+        /// ```
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph").split(',');
-        /// let reader = file.reader();
+        /// let file = FileAPI::from("").split(',').skip_blank_lines(true);
+        /// let reader = file.reader_from("1,2\n\n3,4\n   \n5,6\n".as_bytes())?;
         ///
-        /// // read the value in the 5th line and 7th row :
-        /// // you can consecutive read values:
-        /// let results = reader.read_value(1, 2)
-        ///     .read_value(3, 2)
-        ///     .read_value(2, 1)
-        ///     .execute::<usize>(); // after select the values, you will need to execute and receive the values stored in a [Vec].
+        /// assert_eq!(reader.read_body::<usize>(0, 1)?, vec![vec![1, 2], vec![3, 4]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [comment_char]: FileAPI::comment_char
+        pub fn skip_blank_lines(mut self, skip_blank_lines: bool) -> Self {
+            self.skip_blank_lines = skip_blank_lines;
+            self
+        }
+
+        /// Set how the empty values are handled when a whole line is parsed, such as by
+        /// [Reader::read_line] and [Reader::read_body]. The default is [EmptyFieldPolicy::Keep], so
+        /// an empty value is parsed like the others, which works for [String] but gives a
+        /// [FileError::Parse] for a number.
+        /// # Example
         ///
-        /// assert_eq!(results, vec![2, 8, 4]);
         /// ```
-        pub fn execute<T: FromStr>(&self) -> Vec<T>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            self.values.iter().map(|v| v.trim().parse::<T>().unwrap()).collect::<Vec<T>>()
+        /// use self::simple_file_manager::fileapi::{EmptyFieldPolicy, FileAPI};
+        ///
+        /// let file = FileAPI::from("").split(',').empty_field_policy(EmptyFieldPolicy::SkipParse);
+        /// let reader = file.reader_from("1,2,,".as_bytes())?;
+        ///
+        /// assert_eq!(reader.read_line::<usize>(1)?, vec![1, 2]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn empty_field_policy(mut self, policy: EmptyFieldPolicy) -> Self {
+            self.empty_fields = policy;
+            self
         }
 
-        /// Read the specific lines of header and parse them into a certain type.
+        /// Set the encoding of the file, which is used to decode the file when reading and encode it
+        /// when writing. The default is UTF-8. A byte order mark of the encoding is not read as a part of
+        /// the first value, and is written back by [Changer].
+        ///
+        /// The characters which cannot be written in the encoding are written as HTML numeric
+        /// character references, such as `&#8364;`.
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::{FileAPI, encoding_rs};
+        ///
+        /// let file = FileAPI::from("legacy.csv").split(';').encoding(encoding_rs::WINDOWS_1252);
+        /// let reader = file.reader()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        #[cfg(feature = "encoding_rs")]
+        pub fn encoding(mut self, encoding: &'static Encoding) -> Self {
+            self.encoding = Some(encoding);
+            self
+        }
+
+        /// Get a Reader object for reading several values of the same file in succession.
+        ///
+        /// The file is read every time this function is called, so keep the [Reader] to query the same
+        /// content many times. A byte order mark at the start of the file is skipped, so it is not read
+        /// as a part of the first value.
         ///
         /// # Example
         /// collect a [Reader] type (same with [Builder], [Changer]):
@@ -300,302 +496,5097 @@ pub mod fileapi {
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
         /// let file = FileAPI::from("filename.gph");
-        /// let reader = file.reader();
+        /// let reader = file.reader()?;
         ///
         /// // read the header of the file
-        /// let header = reader.read_header::<usize>(1)[0].clone();
+        /// let header = reader.read_header_line::<usize>()?;
         ///
         /// assert_eq!(header, vec![1, 2, 3]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
         /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
-        pub fn read_header<T: FromStr>(&self, len: usize) -> Vec<Vec<T>>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            if len < 1 {
-                panic!("The 'len' parameter should not less than 1.")
-            }
-            let mut reader = self.lines.lines();
-            let mut header: Vec<Vec<T>> = Vec::new();
-            for _ in 0..len {
-                let line: Vec<T> = Self::read_line_parse(reader.next().unwrap(), self.file.split);
-                header.push(line);
-            }
-            header
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened, or [FileError::InvalidEncoding] if it
+        /// cannot be decoded.
+        pub fn reader(&self) -> Result<Reader<'_>, FileError> {
+            Reader::from(self, false)
         }
 
-        /// Read the last line and parse them into a certain type.
+        /// Get a Reader object like [reader], but the invalid bytes in the file are replaced by the
+        /// replacement character U+FFFD instead of giving an error.
         ///
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let reader = file.reader();
-        ///
-        /// // read the footer of the file
-        /// let footer = reader.read_footer::<usize>();
+        /// // the log may contain a few corrupted bytes.
+        /// let file = FileAPI::from("server.log");
+        /// let text = file.reader_lossy()?.read_to_string();
         ///
-        /// assert_eq!(footer, vec![10, 12]);
+        /// for line in text.lines().filter(|l| l.contains("ERROR")) {
+        ///     println!("{}", line);
+        /// }
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        /// then you will receive a [Vec] recording the value in the last line, which are also parsed to [usize] type.
-        pub fn read_footer<T: FromStr>(&self) -> Vec<T>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            Self::read_line_parse(self.lines.lines().last().unwrap(), self.file.split)
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened.
+        ///
+        /// [reader]: FileAPI::reader
+        pub fn reader_lossy(&self) -> Result<Reader<'_>, FileError> {
+            Reader::from(self, true)
         }
 
-        /// Read the main context and parse them into a certain type.
+        /// Get a Reader object like [reader], but the content is read from the `source`, such as the
+        /// standard input or a buffer in memory, instead of the file. The settings of this [FileAPI],
+        /// such as the split character, are used to read it, and the path is not used.
         ///
         /// # Example
-        /// collect a [Reader] type (same with [Builder], [Changer]):
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let reader = file.reader();
+        /// let config = FileAPI::from("").split(',');
         ///
-        /// // read the body
-        /// // skip the first two line and the last line.
-        /// let body = reader.read_body::<usize>(1, 1);
+        /// // read the piped input.
+        /// let reader = config.reader_from(std::io::stdin())?;
         ///
-        /// assert_eq!(body, vec![vec![4, 5, 6], vec![7, 8, 9]]);
+        /// // read a buffer in memory.
+        /// let reader = config.reader_from("1,2,3\n4,5,6".as_bytes())?;
+        /// assert_eq!(reader.read_line::<usize>(2)?, vec![4, 5, 6]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        /// Then you will receive a [Vec<Vec<usize>>] recording the value in the body, which are also parsed to [usize] type.
-        pub fn read_body<T: FromStr>(&self, header: usize, footer: usize) -> Vec<Vec<T>>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            let mut reader = self.lines.lines();
-            let len = reader.clone().count();
-            let mut context: Vec<Vec<T>> = Vec::new();
-            for i in 0..len - footer {
-                if i < header {
-                    reader.next();
-                    continue;
-                }
-                let line: Vec<T> = Self::read_line_parse(reader.next().unwrap(), self.file.split);
-                context.push(line);
-            }
-            context
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the source cannot be read, or [FileError::InvalidEncoding] if it
+        /// cannot be decoded.
+        ///
+        /// [reader]: FileAPI::reader
+        pub fn reader_from<R: Read>(&self, source: R) -> Result<Reader<'_>, FileError> {
+            let (lines, line_ending) = self.load_from(source, false)?;
+            Ok(Reader::with_lines(self, lines, line_ending, false, false))
         }
 
-        // read a line and parse them into certain type.
-        fn read_line_parse<T: FromStr>(line: &str, split: char) -> Vec<T>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            line.split(split)
-                .collect::<Vec<&str>>()
-                .iter()
-                .map(|s| s.trim().parse::<T>().unwrap())
-                .collect::<Vec<T>>()
+        /// Get a [MmapReader] object, which reads the file by mapping it into memory instead of loading
+        /// it, for the files too large to be loaded.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("huge.csv").split(',');
+        /// let reader = file.mmap_reader()?;
+        ///
+        /// let value = reader.get::<usize>(1_000_000, 2)?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened or mapped, or if an encoding other than
+        /// UTF-8 is set by `FileAPI::encoding`, as the mapped file is read as UTF-8.
+        #[cfg(feature = "memmap")]
+        pub fn mmap_reader(&self) -> Result<MmapReader<'_>, FileError> {
+            MmapReader::from(self)
         }
 
-        /// Count the lines.
+        /// Get a Changer object for modifying several values of the same file in succession.
         ///
         /// # Example
+        /// collect a [Changer] type (same with [Builder], [Reader]):
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let len = FileAPI::from("filename.gph").reader().count_lines();
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let changer = file.changer()?;
         ///
-        /// assert_eq!(len, 4);
+        /// // change the value in the 2th line and 2th row to value '123':
+        /// // you can consecutive change values:
+        /// changer.change_value(2, 2, "123")?
+        ///     .change_value(3, 2, "567")?
+        ///     .change_value(4, 2, "560")?
+        ///     .execute()?;  // after modifying the value, you will need to execute your changes.
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn count_lines(&self) -> usize {
-            self.lines.lines().count()
+        ///
+        /// The file is read here and written by [Changer::execute], so the changes which other processes
+        /// make to the file in the meantime are lost. Use [locked_changer] if several processes change
+        /// the same file.
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened, or [FileError::InvalidEncoding] if it
+        /// cannot be decoded.
+        ///
+        /// [locked_changer]: FileAPI::locked_changer
+        pub fn changer(&self) -> Result<Changer<'_>, FileError> {
+            Changer::from(self, None)
         }
 
-        /// A function to read the specific row in this csv file.
+        /// Get a Changer object like [changer], which holds a lock from reading the file until the
+        /// [Changer] is dropped, so that the other processes getting a [locked_changer] of the same file
+        /// wait until the changes are written.
+        ///
+        /// The lock is taken on a lock file next to the file, whose name is the file name followed by
+        /// `.lock`, as the file itself is replaced when it is written atomically. The lock file is kept
+        /// after the lock is released. The lock is advisory, so it does not stop the processes which do
+        /// not take it, such as the ones using [changer].
         ///
         /// # Example
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// // read the specific row of the csv file.
-        /// let row = FileAPI::from("filename.gph").reader().read_csv::<usize>(5);
+        /// let file = FileAPI::from("counters.csv").split(',');
         ///
-        /// assert_eq!(row, vec![4, 7, 10]);
+        /// // the other processes wait here until the changer is dropped.
+        /// file.locked_changer()?
+        ///     .increment(1, 2, 1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        /// Then you will receive a [Vec] recording the data in this row, which are also parsed to [usize] type.
-        pub fn read_csv<T: FromStr>(&self, row: usize) -> Vec<T>
-            where
-                <T as FromStr>::Err: Debug,
-        {
-            let mut reader = self.lines.lines();
-            reader.next();
-            let data: Vec<T> = reader.map(|l| {
-                l.split(',')
-                    .collect::<Vec<&str>>()[row-1]
-                    .parse::<T>()
-                    .unwrap()
-            }).collect();
-            data
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the lock file cannot be created or locked, or the file cannot be
+        /// opened, or [FileError::InvalidEncoding] if it cannot be decoded.
+        ///
+        /// [changer]: FileAPI::changer
+        /// [locked_changer]: FileAPI::locked_changer
+        pub fn locked_changer(&self) -> Result<Changer<'_>, FileError> {
+            let lock = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(format!("{}.lock", self.path))?;
+            lock.lock()?;
+            Changer::from(self, Some(lock))
         }
-    }
 
-    /// A changer structure for change some specific values in the file in succession.
-    ///
-    /// # Example
-    /// collect a [Changer] type (same with [Builder], [Reader]):
-    /// ```no_run
-    /// use self::simple_file_manager::fileapi::FileAPI;
-    ///
-    /// let file = FileAPI::from("filename.gph");
-    /// let changer = file.changer();
-    ///
-    /// // change the value in the 1th line and 2th row to value '123':
-    /// // you can consecutive change values:
-    /// changer.change_value(1, 2, "234")
-    ///     .change_value(2, 2, "567")
-    ///     .change_value(3, 2, "560")
-    ///     .execute(); // after modifying the value, you will need to execute your changes.
-    /// ```
-    pub struct Changer<'a> {
-        lines: Vec<String>,
-        file: &'a FileAPI
-    }
+        /// Get a Builder object for writing several values for a new file in succession.
+        ///
+        /// # Example
+        /// collect a [Builder] type (same with [Changer], [Reader]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let mut builder = file.builder();
+        ///
+        /// // write a line:
+        /// // you can consecutive write lines:
+        /// builder.write_line("This is the second line.")
+        ///     .write_line("This is the third line.")
+        ///     .write_line("This is the forth line.")
+        ///     .execute()?; // you will also need to execute your changes:
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn builder(&self) -> Builder<'_> {
+            Builder::from(self)
+        }
 
-    impl Changer<'_> {
-        fn from(file: &FileAPI) -> Changer<'_> {
-            let mut the_file = File::open(&file.path).unwrap();
-            let mut lines = String::new();
-            let _ = the_file.read_to_string(&mut lines).unwrap();
-            let lines = lines.lines().collect::<Vec<&str>>().iter().map(|l| l.to_string()).collect();
-            Changer { lines, file }
+        /// Get a [StreamBuilder] object, which writes every line to the file as soon as it is written,
+        /// instead of keeping all the lines until [Builder::execute]. It uses little memory however large
+        /// the file is, but the lines cannot be previewed, and the file is written in place even in
+        /// [atomic] mode.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("large.csv").split(',');
+        /// let mut stream = file.stream_builder()?.write_line("i,square")?;
+        ///
+        /// for i in 0..1_000_000u64 {
+        ///     stream = stream.write_row(&[i, i * i])?;
+        /// }
+        /// stream.finish()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be created.
+        ///
+        /// [atomic]: FileAPI::atomic
+        pub fn stream_builder(&self) -> Result<StreamBuilder<'_>, FileError> {
+            StreamBuilder::from(self)
         }
 
-        /// A function to change a value in this data storage file.
+        /// A function to remove the file and delete the object. Use [truncate] to empty the file but keep
+        /// it existing.
         ///
         /// # Example
-        /// collect a [Changer] type (same with [Builder], [Reader]):
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// FileAPI::from("filename.gph").remove()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be removed, such as when it does not exist.
+        ///
+        /// [truncate]: FileAPI::truncate
+        pub fn remove(&self) -> Result<(), FileError> {
+            Ok(remove_file(&self.path)?)
+        }
+
+        /// A function to empty the file, which is created if it does not exist.
+        ///
+        /// Unlike [remove], the file keeps existing, so the processes which have opened it, such as
+        /// `tail -f`, keep following it.
+        ///
+        /// # Example
+        ///
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
         /// let file = FileAPI::from("filename.gph");
-        /// let changer = file.changer();
+        /// file.truncate()?;
         ///
-        /// // change the value in the 1th line and 2th row to value '123':
-        /// // you can consecutive change values:
-        /// changer.change_value(1, 2, "234")
-        ///     .change_value(2, 2, "567")
-        ///     .change_value(3, 2, "560")
-        ///     .execute(); // after modifying the value, you will need to execute your changes.
-        /// ```
-        pub fn change_value(mut self, line: usize, row: usize, value: &str) -> Self {
-            let a_line = self.lines[line-1].clone();
-            let mut a_line = a_line.split(self.file.split.clone())
-                .collect::<Vec<&str>>();
-            a_line[row-1] = value;
-            self.lines[line-1] =  a_line.join(&*self.file.split.to_string());
-            self
+        /// assert!(file.is_exist());
+        /// assert_eq!(file.reader()?.count_lines(), 0);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened for writing.
+        ///
+        /// [remove]: FileAPI::remove
+        pub fn truncate(&self) -> Result<(), FileError> {
+            File::create(&self.path)?;
+            Ok(())
         }
 
-        /// Confirm and implement the changes.
+        /// A function to move the file to `new_path`, which is replaced if it exists. Returns a
+        /// [FileAPI] of the moved file with the same settings, like [with_path].
         ///
         /// # Example
-        /// collect a [Changer] type (same with [Builder], [Reader]):
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("data.tmp").split(',');
+        /// let file = file.rename("data.csv")?;
+        ///
+        /// assert_eq!(file.path, "data.csv");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be moved, such as when it does not exist.
+        ///
+        /// [with_path]: FileAPI::with_path
+        pub fn rename(&self, new_path: &str) -> Result<FileAPI, FileError> {
+            rename(&self.path, new_path)?;
+            Ok(self.with_path(new_path))
+        }
+
+        /// A function to copy the file to `dest`, which is replaced if it exists. Returns a [FileAPI] of
+        /// the copy with the same settings, like [with_path].
+        ///
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("data.csv").split(',');
+        ///
+        /// // change the copy and keep the original.
+        /// file.copy("data.bak")?
+        ///     .changer()?
+        ///     .delete_line(1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be copied, such as when it does not exist.
+        ///
+        /// [with_path]: FileAPI::with_path
+        pub fn copy(&self, dest: &str) -> Result<FileAPI, FileError> {
+            copy(&self.path, dest)?;
+            Ok(self.with_path(dest))
+        }
+
+        /// A function to check if the file exist.
+        ///
+        /// # Example
+        ///
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
         /// let file = FileAPI::from("filename.gph");
-        /// let changer = file.changer();
+        /// if file.is_exist() {
+        ///     file.remove()?;
+        /// };
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn is_exist(&self) -> bool {
+            Path::new(&self.path).exists()
+        }
+
+        /// A function to get the size of the file in bytes.
         ///
-        /// // change the value in the 1th line and 2th row to value '123':
-        /// // you can consecutive change values:
-        /// changer.change_value(1, 2, "234")
-        ///     .change_value(2, 2, "567")
-        ///     .change_value(3, 2, "560")
-        ///     .execute(); // after modifying the value, you will need to execute your changes.
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let size = FileAPI::from("filename.gph").size()?;
+        ///
+        /// assert_eq!(size, 23);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the metadata of the file cannot be read, such as when it does not
+        /// exist.
+        pub fn size(&self) -> Result<u64, FileError> {
+            Ok(std::fs::metadata(&self.path)?.len())
+        }
+
+        /// A function to get the time when the file was last modified.
+        ///
+        /// # Example
+        ///
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let before = file.modified()?;
+        ///
+        /// file.changer()?.execute()?;
+        ///
+        /// assert!(file.modified()? >= before);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the metadata of the file cannot be read, such as when it does not
+        /// exist, or the modified time is not supported on this platform.
+        pub fn modified(&self) -> Result<SystemTime, FileError> {
+            Ok(std::fs::metadata(&self.path)?.modified()?)
+        }
+
+        /// Get a hash of the bytes of the file as 16 hexadecimal digits, which changes when the content
+        /// of the file changes. It is the 64-bit FNV-1a hash, which is fast but not cryptographic, so
+        /// use it to find changes rather than to verify the files from untrusted sources.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let before = file.hash()?;
+        ///
+        /// file.changer()?.change_value(1, 1, "1")?.execute()?;
+        ///
+        /// // the value was not changed.
+        /// assert_eq!(file.hash()?, before);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn execute(&self) -> &FileAPI {
-            let mut file = File::create(&self.file.path).unwrap();
-            for line in &self.lines {
-                writeln!(file, "{}", line).unwrap();
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be read.
+        pub fn hash(&self) -> Result<String, FileError> {
+            let mut reader = BufReader::new(File::open(&self.path)?);
+            let mut hash: u64 = 0xcbf29ce484222325;
+            loop {
+                let buffer = reader.fill_buf()?;
+                if buffer.is_empty() {
+                    break;
+                }
+                for &byte in buffer {
+                    hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3);
+                }
+                let len = buffer.len();
+                reader.consume(len);
             }
-            self.file
+            Ok(format!("{:016x}", hash))
         }
-    }
 
-    /// A changer class for for writing several values for a new file in succession.
-    ///
-    /// # Example
-    /// collect a [Builder] type (same with [Changer], [Reader]):
-    /// ```no_run
-    /// use self::simple_file_manager::fileapi::FileAPI;
-    ///
-    /// let file = FileAPI::from("filename.gph");
-    /// let mut builder = file.builder();
-    ///
-    /// // write a line:
-    /// // you can consecutive write lines:
-    /// builder.write_line("This is the second line.")
-    ///     .write_line("This is the third line.")
-    ///     .write_line("This is the forth line.")
-    ///     .execute(); // you will also need to execute your changes:
-    ///
-    /// ```
-    pub struct Builder<'a> {
-        lines: Vec<String>,
-        file: &'a FileAPI
-    }
-    impl Builder<'_> {
-        fn from(file: &FileAPI) -> Builder<'_> {
-            Builder {
-                lines: Vec::new(),
-                file
+        /// Compare the lines of this file with the `other` file, and get the differences with their line
+        /// numbers. The files are compared line by line, so a line inserted into the `other` file makes
+        /// all the following lines [DiffKind::Changed].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::{DiffKind, FileAPI};
+        ///
+        /// // the files are "1,2\n3,4" and "1,2\n3,5\n6,7".
+        /// let old = FileAPI::from("old.csv");
+        /// let new = FileAPI::from("new.csv");
+        ///
+        /// assert_eq!(old.diff(&new)?, vec![
+        ///     (2, DiffKind::Changed { old: "3,4".to_string(), new: "3,5".to_string() }),
+        ///     (3, DiffKind::Added("6,7".to_string())),
+        /// ]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if either file cannot be opened, or [FileError::InvalidEncoding] if it
+        /// cannot be decoded.
+        pub fn diff(&self, other: &FileAPI) -> Result<Vec<(usize, DiffKind)>, FileError> {
+            let old = self.reader()?;
+            let new = other.reader()?;
+            let (mut old_lines, mut new_lines) = (old.iter_lines(), new.iter_lines());
+            let mut diffs = Vec::new();
+            for line in 1.. {
+                let kind = match (old_lines.next(), new_lines.next()) {
+                    (Some(o), Some(n)) if o == n => continue,
+                    (Some(o), Some(n)) => DiffKind::Changed { old: o.to_string(), new: n.to_string() },
+                    (Some(o), None) => DiffKind::Removed(o.to_string()),
+                    (None, Some(n)) => DiffKind::Added(n.to_string()),
+                    (None, None) => break,
+                };
+                diffs.push((line, kind));
             }
+            Ok(diffs)
         }
 
-        /// A function to write a new line in the new file.
+        /// Read the lines from `start` to `end`, both included, without loading the whole file. The lines
+        /// before `start` are skipped without decoding them, and the file is not read after `end`, so it
+        /// is fast to read a page of a large file. The lines after the end of the file are not returned.
+        ///
+        /// The file should be encoded in UTF-8, whose lines are ended by `\n`, `\r\n` or `\r`. The encoding set by
+        /// `FileAPI::encoding` is not used, so use a [Reader] for the files in other encodings.
         ///
         /// # Example
-        /// collect a [Builder] type (same with [Changer], [Reader]):
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
         /// let file = FileAPI::from("filename.gph");
-        /// let mut builder = file.builder();
         ///
-        /// // write a line:
-        /// // you can consecutive write lines:
-        /// builder.write_line("This is the second line.")
-        ///     .write_line("This is the third line.")
-        ///     .write_line("This is the forth line.")
-        ///     .execute(); // you will also need to execute your changes:
+        /// assert_eq!(file.read_lines_range(2, 3)?, vec!["4,5,6", "7,8,9"]);
+        /// assert_eq!(file.read_lines_range(4, 100)?, vec!["10,12"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if `start` is 0, [FileError::Io] if the file cannot be
+        /// read or an encoding other than UTF-8 is set, or [FileError::InvalidEncoding] if a line is not
+        /// valid UTF-8.
+        pub fn read_lines_range(&self, start: usize, end: usize) -> Result<Vec<String>, FileError> {
+            let first = start.checked_sub(1).ok_or(FileError::IndexOutOfBounds { line: start, row: 0 })?;
+            self.require_utf8()?;
+            let cr = ends_lines_by_cr(BufReader::new(File::open(&self.path)?))?;
+            let mut reader = BufReader::new(File::open(&self.path)?);
+            // the offset of the current line in the file, for the errors of decoding it.
+            let mut offset = skip_bom(&mut reader)?;
+            let mut buffer = Vec::new();
+            for _ in 0..first {
+                buffer.clear();
+                match read_record(&mut reader, &mut buffer, self.line_quote(), cr)? {
+                    0 => return Ok(Vec::new()),
+                    read => offset += read,
+                }
+            }
+            buffer.clear();
+            let mut lines = Vec::new();
+            for _ in first..end {
+                let read = read_record(&mut reader, &mut buffer, self.line_quote(), cr)?;
+                if read == 0 {
+                    break;
+                }
+                strip_line_ending(&mut buffer, cr);
+                let line = String::from_utf8(std::mem::take(&mut buffer))
+                    .map_err(|e| FileError::InvalidEncoding { byte_offset: offset + e.utf8_error().valid_up_to() })?;
+                lines.push(line);
+                offset += read;
+            }
+            Ok(lines)
+        }
+
+        /// Get a [RowIter] to read the file line by line, without loading the whole file into memory.
+        /// Every line is divided by the split character.
+        ///
+        /// The file is read as UTF-8, and a line which is not valid UTF-8 gives a
+        /// [FileError::InvalidEncoding] item. The encoding set by `FileAPI::encoding` is not used, so use
+        /// a [Reader] for the files in other encodings.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
         ///
+        /// for row in file.row_iter()? {
+        ///     let row = row?;
+        ///     println!("{}", row.join(" "));
+        /// }
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn write_line(mut self, line: &str) -> Self {
-            self.lines.push(line.to_string());
-            self
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened, or if an encoding other than UTF-8 is set.
+        pub fn row_iter(&self) -> Result<RowIter<'_>, FileError> {
+            RowIter::from(self)
         }
 
-        /// Confirm and implement.
+        /// Get a [ParsedIter] to read the file line by line like [row_iter], and parse every line into
+        /// a certain type.
         ///
         /// # Example
-        /// collect a [Builder] type (same with [Changer], [Reader]):
         /// ```no_run
         /// use self::simple_file_manager::fileapi::FileAPI;
         ///
-        /// let file = FileAPI::from("filename.gph");
-        /// let mut builder = file.builder();
+        /// let file = FileAPI::from("filename.gph").split(',');
         ///
-        /// // write a line:
-        /// // you can consecutive write lines:
-        /// builder.write_line("This is the second line.")
-        ///     .write_line("This is the third line.")
-        ///     .write_line("This is the forth line.")
-        ///     .execute(); // you will also need to execute your changes:
+        /// // sum up the lines, skipping the lines which cannot be parsed.
+        /// let sums = file.parsed_iter::<usize>()?
+        ///     .filter_map(|row| row.ok())
+        ///     .map(|row| row.iter().sum::<usize>())
+        ///     .collect::<Vec<usize>>();
         ///
+        /// assert_eq!(sums, vec![6, 15, 24, 22]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
         /// ```
-        pub fn execute(&self) -> &FileAPI {
-            let mut file = File::create(&self.file.path).unwrap();
-            for line in &self.lines {
-                writeln!(file, "{}", line).unwrap();
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened, or if an encoding other than UTF-8 is set.
+        ///
+        /// [row_iter]: FileAPI::row_iter
+        pub fn parsed_iter<T: FromStr>(&self) -> Result<ParsedIter<'_, T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            Ok(ParsedIter { rows: self.row_iter()?, _type: PhantomData })
+        }
+
+        // parse a value into certain type, removing the whitespace around it if trim is on.
+        fn parse_cell<T: FromStr>(&self, value: &str) -> Result<T, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            parse_value::<T>(self.trimmed(value))
+        }
+
+        // parse the values of a line, handling the empty values by the empty field policy.
+        fn parse_values<T: FromStr, S: AsRef<str>>(&self, line: usize, values: &[S]) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            values.iter().enumerate().filter_map(|(j, value)| {
+                let value = value.as_ref();
+                match self.empty_fields {
+                    EmptyFieldPolicy::Error if self.trimmed(value).is_empty() => Some(Err(FileError::EmptyValue { line, row: j + 1 })),
+                    EmptyFieldPolicy::SkipParse if self.trimmed(value).is_empty() => None,
+                    _ => Some(self.parse_cell::<T>(value).map_err(|e| e.at(line, j + 1))),
+                }
+            }).collect()
+        }
+
+        // check if the line is skipped when reading data, as a comment line or a blank line.
+        fn is_skipped(&self, line: &str) -> bool {
+            let line = line.trim_start();
+            self.comment.is_some_and(|c| line.starts_with(c)) || (self.skip_blank_lines && line.is_empty())
+        }
+
+        // the quote character which keeps a line break in a value, which is only honored in csv mode.
+        fn line_quote(&self) -> Option<char> {
+            (self.csv_mode && self.widths.is_none()).then_some(self.quote)
+        }
+
+        // remove the whitespace around a value if trim is on.
+        fn trimmed<'a>(&self, value: &'a str) -> &'a str {
+            if self.trim { value.trim() } else { value }
+        }
+
+        // read all text in the file, with the line ending found in it. The lines ended by a single '\r'
+        // are converted to '\n', so that they can be divided by [str::lines]. In lossy mode, the invalid
+        // bytes are replaced by U+FFFD instead of giving an error.
+        fn load(&self, lossy: bool) -> Result<(String, LineEnding), FileError> {
+            self.load_from(File::open(&self.path)?, lossy)
+        }
+
+        // read all text from the source like [load].
+        fn load_from<R: Read>(&self, mut source: R, lossy: bool) -> Result<(String, LineEnding), FileError> {
+            let mut bytes = Vec::new();
+            let _ = source.read_to_end(&mut bytes)?;
+            Ok(normalize_cr(self.decode(bytes, lossy)?))
+        }
+
+        // give an error if the file has an encoding other than UTF-8, for the readers which read the
+        // bytes of the file as UTF-8 without decoding them.
+        fn require_utf8(&self) -> Result<(), FileError> {
+            #[cfg(feature = "encoding_rs")]
+            if let Some(encoding) = self.encoding.filter(|&e| e != encoding_rs::UTF_8) {
+                let message = format!("a {} file cannot be decoded by this reader, use FileAPI::reader instead", encoding.name());
+                return Err(io::Error::new(io::ErrorKind::Unsupported, message).into());
+            }
+            Ok(())
+        }
+
+        // decode the bytes of the file into text. A byte order mark is decoded as the BOM character like
+        // in UTF-8, so that it is kept by the [Changer].
+        fn decode(&self, bytes: Vec<u8>, lossy: bool) -> Result<String, FileError> {
+            #[cfg(feature = "encoding_rs")]
+            if let Some(encoding) = self.encoding {
+                let (text, had_errors) = encoding.decode_without_bom_handling(&bytes);
+                if had_errors && !lossy {
+                    return Err(FileError::InvalidEncoding { byte_offset: malformed_offset(encoding, &bytes) });
+                }
+                return Ok(text.into_owned());
             }
-            self.file
+            if lossy {
+                return Ok(String::from_utf8_lossy(&bytes).into_owned());
+            }
+            String::from_utf8(bytes).map_err(|e| FileError::InvalidEncoding { byte_offset: e.utf8_error().valid_up_to() })
+        }
+
+        // write the lines to the writer like [write_lines], in the encoding of the file, after a byte
+        // order mark if `bom` is true. The encodings other than UTF-8 and UTF-16 have no byte order mark,
+        // so it is not written in them.
+        fn write_text<'a, W: Write, I: IntoIterator<Item = &'a String>>(&self, writer: &mut W, lines: I, line_ending: LineEnding, trailing_newline: bool, bom: bool) -> io::Result<()> {
+            #[cfg(feature = "encoding_rs")]
+            if let Some(encoding) = self.encoding {
+                let mut text = join_lines(lines, line_ending, trailing_newline);
+                if bom && [encoding_rs::UTF_8, encoding_rs::UTF_16LE, encoding_rs::UTF_16BE].contains(&encoding) {
+                    text.insert(0, BOM);
+                }
+                writer.write_all(&encode(encoding, &text))?;
+                return writer.flush();
+            }
+            let mut writer = BufWriter::new(writer);
+            if bom {
+                write!(writer, "{}", BOM)?;
+            }
+            write_lines(&mut writer, lines, line_ending, trailing_newline)?;
+            writer.flush()
+        }
+
+        // write the file by the given function, and sync it so that the errors of writing to the disk
+        // are returned. In atomic mode, it writes a temporary file and renames it to the file at last.
+        fn write_with<F: FnOnce(&mut File) -> io::Result<()>>(&self, write: F) -> Result<(), FileError> {
+            if !self.atomic {
+                let mut file = File::create(&self.path)?;
+                write(&mut file)?;
+                return Ok(file.sync_all()?);
+            }
+            // a symbolic link is resolved, so that its target is replaced instead of the link itself. The
+            // temporary file has a unique name in the process, so that the writers in other threads do not
+            // use the same one.
+            let target = std::fs::canonicalize(&self.path).unwrap_or_else(|_| PathBuf::from(&self.path));
+            let mut temp = target.clone().into_os_string();
+            temp.push(format!(".{}.{}.tmp", std::process::id(), TEMP_COUNTER.fetch_add(1, AtomicOrdering::Relaxed)));
+            // the temporary file is created with the default mode, so the permissions of the replaced
+            // file are copied to it before the rename.
+            let result = OpenOptions::new().write(true).create_new(true).open(&temp)
+                .and_then(|mut file| {
+                    write(&mut file)?;
+                    if let Ok(metadata) = std::fs::metadata(&target) {
+                        file.set_permissions(metadata.permissions())?;
+                    }
+                    file.sync_all()
+                })
+                .and_then(|_| rename(&temp, &target));
+            if let Err(e) = result {
+                let _ = remove_file(&temp);
+                return Err(e.into());
+            }
+            Ok(())
+        }
+
+        // check if the file to append to is empty, and if its last line has no line ending, which is
+        // written before the new lines so that they are not glued onto the last line. The line ending
+        // of the new lines is the one set by [line_ending], or the one found in the start of the file.
+        fn append_state(&self, file: &mut File) -> io::Result<(bool, bool, LineEnding)> {
+            let len = file.metadata()?.len();
+            if len == 0 {
+                return Ok((true, false, self.line_ending.unwrap_or(LineEnding::Lf)));
+            }
+            let line_ending = match self.line_ending {
+                Some(line_ending) => line_ending,
+                None => {
+                    // the line ending is found in the first 64 KiB, so that a large file is not read.
+                    let mut start = Vec::new();
+                    file.seek(SeekFrom::Start(0))?;
+                    Read::take(&mut *file, 1 << 16).read_to_end(&mut start)?;
+                    LineEnding::detect(&self.decode(start, true).unwrap_or_default())
+                }
+            };
+            #[cfg_attr(not(feature = "encoding_rs"), allow(unused_mut))]
+            let mut breaks = ["\n", "\r"].map(|b| b.as_bytes().to_vec());
+            #[cfg(feature = "encoding_rs")]
+            if let Some(encoding) = self.encoding {
+                breaks = ["\n", "\r"].map(|b| encode(encoding, b));
+            }
+            let mut last = vec![0; breaks[0].len()];
+            if len < last.len() as u64 {
+                return Ok((false, true, line_ending));
+            }
+            file.seek(SeekFrom::End(-(last.len() as i64)))?;
+            file.read_exact(&mut last)?;
+            Ok((false, !breaks.contains(&last), line_ending))
+        }
+
+        // divide a line into values by the split string, honoring the quoted values in csv mode, or by
+        // the widths of the values in fixed-width mode.
+        fn split_line(&self, line: &str) -> Vec<String> {
+            if let Some(widths) = &self.widths {
+                let mut chars = line.chars();
+                return widths.iter().map(|&w| chars.by_ref().take(w).collect()).collect();
+            }
+            if !self.csv_mode {
+                return line.split(self.split.as_str()).map(|s| s.to_string()).collect();
+            }
+            let quote = self.quote;
+            let mut values = Vec::new();
+            let mut value = String::new();
+            let mut quoted = false;
+            let mut rest = line;
+            while let Some(c) = rest.chars().next() {
+                if c == quote {
+                    rest = &rest[c.len_utf8()..];
+                    if quoted && rest.starts_with(quote) {
+                        value.push(quote);
+                        rest = &rest[quote.len_utf8()..];
+                    } else {
+                        quoted = !quoted;
+                    }
+                } else if !quoted && rest.starts_with(self.split.as_str()) {
+                    values.push(std::mem::take(&mut value));
+                    rest = &rest[self.split.len()..];
+                } else {
+                    value.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+            values.push(value);
+            values
+        }
+
+        // divide a line into values like [split_line], with the characters after the last column of a
+        // fixed-width line as one more value, so that they are kept when the line is joined again.
+        fn split_line_all(&self, line: &str) -> Vec<String> {
+            let mut values = self.split_line(line);
+            if let Some(widths) = &self.widths {
+                let rest = line.chars().skip(widths.iter().sum()).collect::<String>();
+                if !rest.is_empty() {
+                    values.push(rest);
+                }
+            }
+            values
+        }
+
+        // join the values into a line by the split string, quoting the values which need it in csv mode,
+        // or padding the values to their widths in fixed-width mode.
+        fn join_line<S: AsRef<str>>(&self, values: &[S]) -> String {
+            if let Some(widths) = &self.widths {
+                return values.iter()
+                    .enumerate()
+                    .map(|(j, v)| format!("{:<width$}", v.as_ref(), width = widths.get(j).copied().unwrap_or(0)))
+                    .collect();
+            }
+            values.iter()
+                .map(|v| self.quote_value(v.as_ref()))
+                .collect::<Vec<String>>()
+                .join(&self.split)
+        }
+
+        // quote a value in csv mode if it contains the split string, a quote or a line break.
+        fn quote_value(&self, value: &str) -> String {
+            let quote = self.quote;
+            if self.csv_mode && (value.contains(self.split.as_str()) || value.contains(quote) || value.contains(['\n', '\r'])) {
+                format!("{}{}{}", quote, value.replace(quote, &quote.to_string().repeat(2)), quote)
+            } else {
+                value.to_string()
+            }
+        }
+    }
+
+    impl Clone for FileAPI {
+        fn clone(&self) -> Self {
+            FileAPI {
+                path: self.path.clone(),
+                split: self.split.clone(),
+                csv_mode: self.csv_mode,
+                trim: self.trim,
+                atomic: self.atomic,
+                line_ending: self.line_ending,
+                comment: self.comment,
+                skip_blank_lines: self.skip_blank_lines,
+                empty_fields: self.empty_fields,
+                quote: self.quote,
+                widths: self.widths.clone(),
+                #[cfg(feature = "encoding_rs")]
+                encoding: self.encoding
+            }
+        }
+    }
+
+    /// A reader structure for reading specific values of the same file in succession.
+    ///
+    /// # Example
+    /// collect a [Reader] type (same with [Builder], [Changer]):
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph");
+    /// let reader = file.reader()?;
+    ///
+    /// // read the header of the file
+    /// let header = reader.read_header_line::<usize>()?;
+    ///
+    /// assert_eq!(header, vec![1, 2, 3]);
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
+    ///
+    /// The whole file is read once when the [Reader] is created, and all the queries use this cached
+    /// content without reading the file again. It means the changes made to the file later, such as by
+    /// a [Changer], are not seen until the [Reader] is [reload]ed.
+    ///
+    /// The positions of the lines in the content are also found once, so a line is read at once
+    /// without going through the lines before it. To read another content with the same settings, use
+    /// [Reader::from_string].
+    ///
+    /// [reload]: Reader::reload
+    pub struct Reader<'a> {
+        lines: String,
+        file: &'a FileAPI,
+        pub values: Vec<String>,
+        lossy: bool,
+        from_file: bool,
+        bounds: Vec<(usize, usize)>,
+        line_ending: LineEnding,
+        bom: bool
+    }
+
+    impl<'a> Reader<'a> {
+        fn from(file: &FileAPI, lossy: bool) -> Result<Reader<'_>, FileError> {
+            let (lines, line_ending) = file.load(lossy)?;
+            Ok(Reader::with_lines(file, lines, line_ending, lossy, true))
+        }
+
+        fn with_lines(file: &FileAPI, mut lines: String, line_ending: LineEnding, lossy: bool, from_file: bool) -> Reader<'_> {
+            let bom = strip_bom(&mut lines);
+            let bounds = line_bounds(lines.as_bytes(), file.line_quote(), false);
+            Reader { lines, file , values: Vec::new(), lossy, from_file, bounds, line_ending, bom }
+        }
+
+        // get a line by its index from 0.
+        fn nth_line(&self, i: usize) -> Option<&str> {
+            self.bounds.get(i).and_then(|&(start, end)| self.lines.get(start..end))
+        }
+
+        /// Get a Reader object reading the `content` with the settings of the `file`, such as the split
+        /// character, without touching the file system. It is useful for testing the parsing of a format
+        /// without a file.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::{FileAPI, Reader};
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = Reader::from_string(&file, "\u{feff}1,2,3\n4,5,6");
+        ///
+        /// assert_eq!(reader.read_line::<usize>(1)?, vec![1, 2, 3]);
+        /// assert_eq!(reader.read_line::<usize>(2)?, vec![4, 5, 6]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn from_string(file: &'a FileAPI, content: &str) -> Reader<'a> {
+            let (lines, line_ending) = normalize_cr(content.to_string());
+            Reader::with_lines(file, lines, line_ending, false, false)
+        }
+
+        /// Get a [Changer] object of the content read by this [Reader], like [FileAPI::changer] but
+        /// without reading the file again. The changes are written to the file on [Changer::execute],
+        /// even if the content was read from another source by [FileAPI::reader_from].
+        ///
+        /// The invalid bytes replaced by [FileAPI::reader_lossy] are written as U+FFFD.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // delete the last line if it is ragged.
+        /// if !reader.is_rectangular() {
+        ///     let last = reader.count_lines();
+        ///     reader.into_changer().delete_line(last)?.execute()?;
+        /// }
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn into_changer(self) -> Changer<'a> {
+            Changer::with_lines(self.file, &self.lines, self.line_ending, self.bom, None)
+        }
+
+        /// Read the file again to update the cached content. The selected values are kept.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let mut reader = file.reader()?;
+        ///
+        /// file.changer()?.change_value(1, 1, "100")?.execute()?;
+        /// assert_eq!(reader.read_line::<usize>(1)?, vec![1, 2, 3]);
+        ///
+        /// reader.reload()?;
+        /// assert_eq!(reader.read_line::<usize>(1)?, vec![100, 2, 3]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be opened, or [FileError::InvalidEncoding] if it
+        /// cannot be decoded. The cached content is kept on error.
+        ///
+        /// Returns [FileError::Io] as well if the [Reader] was read from a source by
+        /// [FileAPI::reader_from] or [Reader::from_string], which cannot be read again.
+        pub fn reload(&mut self) -> Result<(), FileError> {
+            if !self.from_file {
+                return Err(io::Error::new(io::ErrorKind::Unsupported, "the reader was not read from the file").into());
+            }
+            let (mut lines, line_ending) = self.file.load(self.lossy)?;
+            self.bom = strip_bom(&mut lines);
+            self.bounds = line_bounds(lines.as_bytes(), self.file.line_quote(), false);
+            self.lines = lines;
+            self.line_ending = line_ending;
+            Ok(())
+        }
+
+        /// Read all text in the file.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // read all the lines in the file
+        /// let context = reader.read_to_string();
+        ///
+        /// assert_eq!(context, String::from("1,2,3\n4,5,6\n7,8,9\n10,12"));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn read_to_string(&self) -> String {
+            self.lines.to_string()
+        }
+
+        /// Get all text in the file like [read_to_string], without copying it.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::{FileAPI, Reader};
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = Reader::from_string(&file, "1,2\n3,4");
+        ///
+        /// assert_eq!(reader.content(), "1,2\n3,4");
+        /// ```
+        ///
+        /// [read_to_string]: Reader::read_to_string
+        pub fn content(&self) -> &str {
+            &self.lines
+        }
+
+        /// Iterate over the lines in the file as they are, without dividing or parsing them. The line
+        /// endings are not included.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // find the lines containing a '5'
+        /// let lines = reader.iter_lines().filter(|l| l.contains('5')).collect::<Vec<&str>>();
+        ///
+        /// assert_eq!(lines, vec!["4,5,6"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn iter_lines(&self) -> impl Iterator<Item = &str> {
+            self.bounds.iter().map(|&(start, end)| &self.lines[start..end])
+        }
+
+        /// A function to read a value in this data storage file.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the value in the 5th line and 7th row :
+        /// // you can consecutive read values:
+        /// let results = reader.read_value(1, 2)?
+        ///     .read_value(3, 2)?
+        ///     .read_value(2, 1)?
+        ///     .execute::<usize>()?; // after select the values, you will need to execute and receive the values stored in a [Vec].
+        ///
+        /// assert_eq!(results, vec![2, 8, 4]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at this position, including
+        /// when `line` or `row` is 0.
+        pub fn read_value(mut self, line:usize, row:usize) -> Result<Self, FileError> {
+            let value = self.value_at(line, row)?;
+            self.values.push(value);
+            Ok(self)
+        }
+
+        /// Read a single value and parse it into a certain type, without consuming the [Reader].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the value in the 2th line and 3th row:
+        /// let value = reader.get::<usize>(2, 3)?;
+        ///
+        /// assert_eq!(value, 6);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at this position, or
+        /// [FileError::Parse] if the value cannot be parsed into `T`.
+        pub fn get<T: FromStr>(&self, line: usize, row: usize) -> Result<T, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.file.parse_cell::<T>(&self.value_at(line, row)?).map_err(|e| e.at(line, row))
+        }
+
+        /// Read the values at several positions, given as `(line, row)`, like [read_value], but a value
+        /// which is empty, missing, or cannot be parsed into `T` is read as [None], so one bad position
+        /// does not lose the others.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // the file has no 9th line.
+        /// let values = reader.read_values_opt::<usize>(&[(1, 2), (9, 1), (2, 1)]);
+        ///
+        /// assert_eq!(values, vec![Some(2), None, Some(4)]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [read_value]: Reader::read_value
+        pub fn read_values_opt<T: FromStr>(&self, positions: &[(usize, usize)]) -> Vec<Option<T>> {
+            positions.iter()
+                .map(|&(line, row)| {
+                    let value = self.value_at(line, row).ok()?;
+                    let value = self.file.trimmed(&value);
+                    if value.is_empty() { None } else { value.parse::<T>().ok() }
+                })
+                .collect()
+        }
+
+        // find the value at this position. The line and row start from 1, so 0 is out of bounds.
+        fn value_at(&self, line: usize, row: usize) -> Result<String, FileError> {
+            line.checked_sub(1)
+                .and_then(|i| self.nth_line(i))
+                .zip(row.checked_sub(1))
+                .and_then(|(l, j)| self.file.split_line(l).into_iter().nth(j))
+                .ok_or(FileError::IndexOutOfBounds { line, row })
+        }
+
+        /// A function to read a value counting from the end of the file, where the line 1 is the last
+        /// line and the row 1 is the last value of that line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the last value of the last line, and the first value of the second to last line.
+        /// // it can be mixed with the values counted from the start.
+        /// let results = reader.read_value_rev(1, 1)?
+        ///     .read_value_rev(2, 3)?
+        ///     .read_value(1, 1)?
+        ///     .execute::<usize>()?;
+        ///
+        /// assert_eq!(results, vec![12, 7, 1]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at this position. The
+        /// position in the error is also counted from the end.
+        pub fn read_value_rev(mut self, line: usize, row: usize) -> Result<Self, FileError> {
+            let value = line.checked_sub(1)
+                .and_then(|i| self.count_lines().checked_sub(i + 1))
+                .and_then(|i| self.nth_line(i))
+                .zip(row.checked_sub(1))
+                .and_then(|(l, j)| self.file.split_line(l).into_iter().rev().nth(j))
+                .ok_or(FileError::IndexOutOfBounds { line, row })?;
+            self.values.push(value);
+            Ok(self)
+        }
+
+        /// Confirm and receive the selected values.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the value in the 5th line and 7th row :
+        /// // you can consecutive read values:
+        /// let results = reader.read_value(1, 2)?
+        ///     .read_value(3, 2)?
+        ///     .read_value(2, 1)?
+        ///     .execute::<usize>()?; // after select the values, you will need to execute and receive the values stored in a [Vec].
+        ///
+        /// assert_eq!(results, vec![2, 8, 4]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Parse] if any selected value cannot be parsed into `T`.
+        pub fn execute<T: FromStr>(&self) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.values.iter().map(|v| self.file.parse_cell::<T>(v)).collect()
+        }
+
+        /// Read a line and parse it into a certain type.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the 2th line of the file
+        /// let line = reader.read_line::<usize>(2)?;
+        ///
+        /// assert_eq!(line, vec![4, 5, 6]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line, or [FileError::Parse]
+        /// if a value cannot be parsed into `T`.
+        pub fn read_line<T: FromStr>(&self, line: usize) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let a_line = line.checked_sub(1)
+                .and_then(|i| self.nth_line(i))
+                .ok_or(FileError::IndexOutOfBounds { line, row: 0 })?;
+            Self::read_line_parse(line, a_line, self.file)
+        }
+
+        /// Read the specific lines of header and parse them into a certain type.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // read the header of the file
+        /// let header = reader.read_header::<usize>(1)?[0].clone();
+        ///
+        /// assert_eq!(header, vec![1, 2, 3]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// then you will receive a [Vec] recording the value in the first line, which are also parsed to [usize] type.
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has less than `len` lines, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        pub fn read_header<T: FromStr>(&self, len: usize) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            if len < 1 {
+                panic!("The 'len' parameter should not less than 1.")
+            }
+            let mut reader = self.iter_lines();
+            let mut header: Vec<Vec<T>> = Vec::new();
+            for i in 0..len {
+                let a_line = reader.next().ok_or(FileError::IndexOutOfBounds { line: i + 1, row: 0 })?;
+                let line: Vec<T> = Self::read_line_parse(i + 1, a_line, self.file)?;
+                header.push(line);
+            }
+            Ok(header)
+        }
+
+        /// Read the first line as a header and parse it into a certain type, which is the same as the
+        /// only line of [read_header] with `len` 1.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// let header = reader.read_header_line::<usize>()?;
+        ///
+        /// assert_eq!(header, vec![1, 2, 3]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file is empty, or [FileError::Parse] if a value
+        /// cannot be parsed into `T`.
+        ///
+        /// [read_header]: Reader::read_header
+        pub fn read_header_line<T: FromStr>(&self) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.read_line(1)
+        }
+
+        /// Read the specific lines of header like [read_header], but keep the values as they are in the
+        /// file, without trimming or parsing them.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// let header = reader.read_header_str(1)?;
+        ///
+        /// assert_eq!(header, vec![vec!["1", "2", "3"]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has less than `len` lines.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len` is 0.
+        ///
+        /// [read_header]: Reader::read_header
+        pub fn read_header_str(&self, len: usize) -> Result<Vec<Vec<String>>, FileError> {
+            if len < 1 {
+                panic!("The 'len' parameter should not less than 1.")
+            }
+            let mut reader = self.iter_lines();
+            (0..len).map(|i| {
+                reader.next()
+                    .map(|l| self.file.split_line(l))
+                    .ok_or(FileError::IndexOutOfBounds { line: i + 1, row: 0 })
+            }).collect()
+        }
+
+        /// Read the last line and parse them into a certain type.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // read the footer of the file
+        /// let footer = reader.read_footer::<usize>()?;
+        ///
+        /// assert_eq!(footer, vec![10, 12]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// then you will receive a [Vec] recording the value in the last line, which are also parsed to [usize] type.
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::EmptyFile] if the file has no lines, or [FileError::Parse] if a
+        /// value cannot be parsed into `T`.
+        pub fn read_footer<T: FromStr>(&self) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let last = self.count_lines().checked_sub(1).ok_or(FileError::EmptyFile)?;
+            Self::read_line_parse(last + 1, self.nth_line(last).unwrap_or_default(), self.file)
+        }
+
+        /// Read the last `n` lines and parse them into a certain type, such as a footer of several lines.
+        /// The lines are in the same order as in the file.
+        ///
+        /// If the file has less than `n` lines, all the lines are returned.
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // read the last two lines of the file
+        /// let footer = reader.read_footer_n::<usize>(2)?;
+        ///
+        /// assert_eq!(footer, vec![vec![7, 8, 9], vec![10, 12]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `n` is 0.
+        pub fn read_footer_n<T: FromStr>(&self, n: usize) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            if n < 1 {
+                panic!("The 'n' parameter should not less than 1.")
+            }
+            (self.count_lines().saturating_sub(n)..self.count_lines())
+                .map(|i| Self::read_line_parse(i + 1, self.nth_line(i).unwrap_or_default(), self.file))
+                .collect()
+        }
+
+        /// Read the main context and parse them into a certain type.
+        ///
+        /// # Example
+        /// collect a [Reader] type (same with [Builder], [Changer]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// // read the body
+        /// // skip the first two line and the last line.
+        /// let body = reader.read_body::<usize>(1, 1)?;
+        ///
+        /// assert_eq!(body, vec![vec![4, 5, 6], vec![7, 8, 9]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// Then you will receive a [Vec<Vec<usize>>] recording the value in the body, which are also parsed to [usize] type.
+        ///
+        /// The last line is read whether or not the file ends with a newline, and the body is empty if
+        /// `header` and `footer` cover all the lines:
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// for text in ["1,2\n3,4\n5,6\n", "1,2\n3,4\n5,6"] {
+        ///     let reader = file.reader_from(text.as_bytes())?;
+        ///
+        ///     assert_eq!(reader.read_body::<usize>(0, 0)?, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        ///     assert_eq!(reader.read_body::<usize>(1, 0)?, vec![vec![3, 4], vec![5, 6]]);
+        ///     assert_eq!(reader.read_body::<usize>(0, 1)?, vec![vec![1, 2], vec![3, 4]]);
+        ///     assert_eq!(reader.read_body::<usize>(1, 1)?, vec![vec![3, 4]]);
+        ///     assert_eq!(reader.read_body::<usize>(2, 2)?, Vec::<Vec<usize>>::new());
+        ///     assert_eq!(reader.read_body::<usize>(0, 4)?, Vec::<Vec<usize>>::new());
+        /// }
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Parse] if a value cannot be parsed into `T`, with the line and row of the
+        /// value.
+        /// ```
+        /// use self::simple_file_manager::fileapi::{FileAPI, FileError};
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = file.reader_from("a,b\n1,2\n3,x".as_bytes())?;
+        ///
+        /// assert!(matches!(reader.read_body::<usize>(1, 0), Err(FileError::Parse { line: 3, row: 2, value, .. }) if value == "x"));
+        /// # Ok::<(), FileError>(())
+        /// ```
+        pub fn read_body<T: FromStr>(&self, header: usize, footer: usize) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.body_lines(header, footer)
+                .into_iter()
+                .map(|(line, l)| Self::read_line_parse(line, l, self.file))
+                .collect()
+        }
+
+        /// Read the main context like [read_body], but parse every value by the `parser` function instead
+        /// of [FromStr], for the formats which cannot be parsed by it, such as dates or prices with a
+        /// currency sign. The values are trimmed before parsing if trim is on.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::{FileAPI, FileError};
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = file.reader_from("apple,banana\n$3.50,$0.25".as_bytes())?;
+        ///
+        /// let prices = reader.read_body_with(1, 0, |v| {
+        ///     v.strip_prefix('$')
+        ///         .and_then(|p| p.parse::<f64>().ok())
+        ///         .ok_or_else(|| FileError::Parse { line: 0, row: 0, value: v.to_string(), message: "not a price".to_string() })
+        /// })?;
+        ///
+        /// assert_eq!(prices, vec![vec![3.5, 0.25]]);
+        /// # Ok::<(), FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns the first error given by the `parser`. A [FileError::Parse] without a position, whose
+        /// `line` is 0, is given the position of the value.
+        ///
+        /// [read_body]: Reader::read_body
+        pub fn read_body_with<T, F: Fn(&str) -> Result<T, FileError>>(&self, header: usize, footer: usize, parser: F) -> Result<Vec<Vec<T>>, FileError> {
+            self.body_lines(header, footer)
+                .into_iter()
+                .map(|(line, l)| {
+                    self.file.split_line(l)
+                        .iter()
+                        .enumerate()
+                        .map(|(j, v)| parser(self.file.trimmed(v)).map_err(|e| e.at(line, j + 1)))
+                        .collect()
+                })
+                .collect()
+        }
+
+        /// Read the main context like [read_body], but parse the lines in several threads, which is
+        /// faster for the large files with many values. The lines are in the same order as [read_body].
+        ///
+        /// The bodies with less than 1024 lines are parsed in the current thread, as they are parsed
+        /// faster than the threads are started.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("matrix.txt").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// let body = reader.read_body_parallel::<f64>(1, 0)?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_body]: Reader::read_body
+        #[cfg(feature = "rayon")]
+        pub fn read_body_parallel<T: FromStr + Send>(&self, header: usize, footer: usize) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let lines = self.body_lines(header, footer);
+            if lines.len() < 1024 {
+                return lines.into_iter().map(|(line, l)| Self::read_line_parse(line, l, self.file)).collect();
+            }
+            lines.into_par_iter().map(|(line, l)| Self::read_line_parse(line, l, self.file)).collect()
+        }
+
+        // the lines which are not skipped as comments or blank lines, with their line numbers.
+        fn data_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+            self.iter_lines()
+                .enumerate()
+                .filter(|(_, l)| !self.file.is_skipped(l))
+                .map(|(i, l)| (i + 1, l))
+        }
+
+        // the lines between the header and the footer, with their line numbers. The skipped lines are
+        // not counted.
+        fn body_lines(&self, header: usize, footer: usize) -> Vec<(usize, &str)> {
+            let lines = self.data_lines().collect::<Vec<(usize, &str)>>();
+            let end = lines.len().saturating_sub(footer);
+            lines.get(header..end).unwrap_or_default().to_vec()
+        }
+
+        /// Read the main context like [read_body], but make sure that all the lines have the same
+        /// number of values as the first line of the body. The empty values are counted even if they are
+        /// left out by [EmptyFieldPolicy::SkipParse].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::{FileAPI, FileError};
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // the body including the last line is ragged.
+        /// let body = reader.read_body_strict::<usize>(1, 0);
+        ///
+        /// assert!(matches!(body, Err(FileError::RaggedRow { line: 4, expected: 3, found: 2 })));
+        /// # Ok::<(), FileError>(())
+        /// ```
+        ///
+        /// ```
+        /// use self::simple_file_manager::fileapi::{EmptyFieldPolicy, FileAPI};
+        ///
+        /// let file = FileAPI::from("").split(',').empty_field_policy(EmptyFieldPolicy::SkipParse);
+        /// let reader = file.reader_from("1,2,3\n4,,6".as_bytes())?;
+        ///
+        /// assert_eq!(reader.read_body_strict::<usize>(0, 0)?, vec![vec![1, 2, 3], vec![4, 6]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::RaggedRow] if a line has a different number of values, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_body]: Reader::read_body
+        pub fn read_body_strict<T: FromStr>(&self, header: usize, footer: usize) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let mut body: Vec<Vec<T>> = Vec::new();
+            // the values are counted before the empty values are left out by the empty field policy.
+            let mut expected = None;
+            for (line, l) in self.body_lines(header, footer) {
+                let values = self.file.split_line(l);
+                let expected = *expected.get_or_insert(values.len());
+                if values.len() != expected {
+                    return Err(FileError::RaggedRow { line, expected, found: values.len() });
+                }
+                body.push(self.file.parse_values(line, &values)?);
+            }
+            Ok(body)
+        }
+
+        /// Read the main context like [read_body], but keep the values as they are in the file, without
+        /// trimming or parsing them.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// let body = reader.read_body_str(1, 1);
+        ///
+        /// assert_eq!(body, vec![vec!["4", "5", "6"], vec!["7", "8", "9"]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [read_body]: Reader::read_body
+        pub fn read_body_str(&self, header: usize, footer: usize) -> Vec<Vec<String>> {
+            self.body_lines(header, footer)
+                .into_iter()
+                .map(|(_, l)| self.file.split_line(l))
+                .collect()
+        }
+
+        /// Divide every line of the file into string values at once, so that the n-th line of the
+        /// result is the n-th line of the file. Unlike [read_body_str], the comment and blank lines are
+        /// kept.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = file.reader_from("a,b\n1,2\n3,4".as_bytes())?;
+        ///
+        /// let grid = reader.as_grid();
+        ///
+        /// assert_eq!(grid[0], vec!["a", "b"]);
+        /// assert_eq!(grid[2][1], "4");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [read_body_str]: Reader::read_body_str
+        pub fn as_grid(&self) -> Vec<Vec<String>> {
+            self.iter_lines().map(|l| self.file.split_line(l)).collect()
+        }
+
+        /// Read the lines in the given range and parse them into a certain type. It is the same as
+        /// [read_body], but takes the 1-based line numbers of the first and last line instead of the
+        /// number of lines to skip from the top and bottom.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the 2th and 3th lines of the file
+        /// let rows = reader.read_rows::<usize>(2..=3)?;
+        ///
+        /// assert_eq!(rows, vec![vec![4, 5, 6], vec![7, 8, 9]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if any line in the range is outside the file, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_body]: Reader::read_body
+        pub fn read_rows<T: FromStr>(&self, lines: RangeInclusive<usize>) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            lines.map(|line| {
+                let a_line = line.checked_sub(1)
+                    .and_then(|i| self.nth_line(i))
+                    .ok_or(FileError::IndexOutOfBounds { line, row: 0 })?;
+                Self::read_line_parse(line, a_line, self.file)
+            }).collect()
+        }
+
+        /// Read a block of values from the lines and rows in the given ranges, and parse them into a
+        /// certain type.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the 2th and 3th values of the 2th and 3th lines.
+        /// let block = reader.read_range::<usize>(2..=3, 2..=3)?;
+        ///
+        /// assert_eq!(block, vec![vec![5, 6], vec![8, 9]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if any position in the ranges is outside the file, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        pub fn read_range<T: FromStr>(&self, lines: RangeInclusive<usize>, rows: RangeInclusive<usize>) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let mut block: Vec<Vec<T>> = Vec::new();
+            for line in lines {
+                let a_line = line.checked_sub(1)
+                    .and_then(|i| self.nth_line(i))
+                    .map(|l| self.file.split_line(l))
+                    .ok_or(FileError::IndexOutOfBounds { line, row: 0 })?;
+                let values = rows.clone().map(|row| {
+                    let value = row.checked_sub(1)
+                        .and_then(|j| a_line.get(j))
+                        .ok_or(FileError::IndexOutOfBounds { line, row })?;
+                    self.file.parse_cell::<T>(value).map_err(|e| e.at(line, row))
+                }).collect::<Result<Vec<T>, FileError>>()?;
+                block.push(values);
+            }
+            Ok(block)
+        }
+
+        /// Read all the lines, parse them into a certain type, and flip the lines and rows, so that the
+        /// n-th line of the result is the n-th row of the file.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "1,2,3\n4,5,6".
+        /// let file = FileAPI::from("matrix.txt").split(',');
+        /// let columns = file.reader()?.transpose::<usize>()?;
+        ///
+        /// assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::RaggedRow] if the lines have different numbers of values, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        pub fn transpose<T: FromStr>(&self) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let body = self.read_body_strict::<T>(0, 0)?;
+            // the empty values left out by the empty field policy can still make the lines ragged.
+            if let Some(expected) = body.first().map(|l| l.len()) {
+                if let Some(i) = body.iter().position(|l| l.len() != expected) {
+                    let line = self.body_lines(0, 0)[i].0;
+                    return Err(FileError::RaggedRow { line, expected, found: body[i].len() });
+                }
+            }
+            Ok(transpose(body))
+        }
+
+        // read a line and parse them into certain type. `line` is the line number for the errors.
+        fn read_line_parse<T: FromStr>(line: usize, text: &str, file: &FileAPI) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            file.parse_values(line, &file.split_line(text))
+        }
+
+        /// Count the lines.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let len = FileAPI::from("filename.gph").reader()?.count_lines();
+        ///
+        /// assert_eq!(len, 4);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn count_lines(&self) -> usize {
+            self.bounds.len()
+        }
+
+        /// Get the byte offset of the start of every line in the loaded content, which is given by
+        /// [content], so the n-th line starts at the n-th offset. The offsets are the same as in the file
+        /// only if the file is UTF-8 without a byte order mark.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let reader = file.reader_from("1,2\r\n30,40\n5".as_bytes())?;
+        ///
+        /// let offsets = reader.line_offsets();
+        ///
+        /// assert_eq!(offsets, vec![0, 5, 11]);
+        /// assert!(reader.content()[offsets[1]..].starts_with("30,40"));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [content]: Reader::content
+        pub fn line_offsets(&self) -> Vec<usize> {
+            self.bounds.iter().map(|&(start, _)| start).collect()
+        }
+
+        /// Count the lines which are not blank, that is the lines containing anything but whitespace.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "1,2\n\n3,4\n".
+        /// let file = FileAPI::from("sections.txt");
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.count_lines(), 3);
+        /// assert_eq!(reader.count_non_blank(), 2);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn count_non_blank(&self) -> usize {
+            self.iter_lines().filter(|l| !l.trim().is_empty()).count()
+        }
+
+        /// Count the lines of the main context, which are read by [read_body] with the same `header` and
+        /// `footer`. The comment lines, and the blank lines if they are skipped, are not counted.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',').comment_char('#');
+        /// let reader = file.reader_from("a,b\n# note\n1,2\n3,4\ntotal,6".as_bytes())?;
+        ///
+        /// assert_eq!(reader.count_data_lines(1, 1), 2);
+        /// assert_eq!(reader.count_data_lines(1, 1), reader.read_body::<usize>(1, 1)?.len());
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [read_body]: Reader::read_body
+        pub fn count_data_lines(&self, header: usize, footer: usize) -> usize {
+            self.data_lines().count().saturating_sub(header).saturating_sub(footer)
+        }
+
+        /// Get the names of the columns in the header line, which is the first line of the file not
+        /// skipped as a comment line or a blank line. The names are trimmed if trim is on, the default. An
+        /// empty file has no names.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',').comment_char('#');
+        /// let reader = file.reader_from("# prices\nname, price\napple,3".as_bytes())?;
+        ///
+        /// assert_eq!(reader.header_names(), vec!["name", "price"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn header_names(&self) -> Vec<String> {
+            self.data_lines()
+                .next()
+                .map(|(_, l)| self.file.split_line(l).iter().map(|column| self.file.trimmed(column).to_string()).collect())
+                .unwrap_or_default()
+        }
+
+        /// Find the row of a column by its name in the header line, like [header_names].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the first line of the file is "name,price".
+        /// let file = FileAPI::from("prices.csv").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.column_index("price"), Some(2));
+        /// assert_eq!(reader.column_index("weight"), None);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [header_names]: Reader::header_names
+        pub fn column_index(&self, name: &str) -> Option<usize> {
+            self.header_names()
+                .iter()
+                .position(|column| column == name)
+                .map(|i| i + 1)
+        }
+
+        /// A function to read a value like [read_value], but the row is found by its name in the header
+        /// line. The line number still counts the header line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "name,price\napple,3\npear,5".
+        /// let file = FileAPI::from("prices.csv").split(',');
+        /// let results = file.reader()?
+        ///     .read_value_by_name(3, "price")?
+        ///     .read_value_by_name(2, "price")?
+        ///     .execute::<usize>()?;
+        ///
+        /// assert_eq!(results, vec![5, 3]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::UnknownColumn] if there is no such column, or
+        /// [FileError::IndexOutOfBounds] if the file has no value at this position.
+        ///
+        /// [read_value]: Reader::read_value
+        pub fn read_value_by_name(self, line: usize, name: &str) -> Result<Self, FileError> {
+            let row = self.column_index(name).ok_or_else(|| FileError::UnknownColumn(name.to_string()))?;
+            self.read_value(line, row)
+        }
+
+        /// Read a column like [read_column] without the header line, where the row is found by its name
+        /// in the header line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "name,price\napple,3\npear,5".
+        /// let file = FileAPI::from("prices.csv").split(',');
+        /// let prices = file.reader()?.read_column_by_name::<usize>("price")?;
+        ///
+        /// assert_eq!(prices, vec![3, 5]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::UnknownColumn] if there is no such column,
+        /// [FileError::IndexOutOfBounds] if a line has no value in this row, or [FileError::Parse] if a
+        /// value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn read_column_by_name<T: FromStr>(&self, name: &str) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let row = self.column_index(name).ok_or_else(|| FileError::UnknownColumn(name.to_string()))?;
+            self.read_column(row, true)
+        }
+
+        /// Count the values of a line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let len = FileAPI::from("filename.gph").split(',').reader()?.count_columns(4)?;
+        ///
+        /// assert_eq!(len, 2);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn count_columns(&self, line: usize) -> Result<usize, FileError> {
+            line.checked_sub(1)
+                .and_then(|i| self.nth_line(i))
+                .map(|l| self.file.split_line(l).len())
+                .ok_or(FileError::IndexOutOfBounds { line, row: 0 })
+        }
+
+        /// Get the number of lines, and the number of values of every line if all the lines have the
+        /// same number of values. The second one is [None] if the lines are ragged or the file is empty.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let (lines, columns) = FileAPI::from("filename.gph").split(',').reader()?.dimensions();
+        ///
+        /// // the last line only has 2 values.
+        /// assert_eq!((lines, columns), (4, None));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn dimensions(&self) -> (usize, Option<usize>) {
+            let mut columns = self.iter_lines().map(|l| self.file.split_line(l).len());
+            let first = columns.next();
+            let uniform = first.filter(|&n| columns.all(|c| c == n));
+            (self.count_lines(), uniform)
+        }
+
+        /// Check if all the lines have the same number of values. An empty file is rectangular.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// assert!(!file.reader()?.is_rectangular());
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn is_rectangular(&self) -> bool {
+            match self.dimensions() {
+                (0, _) => true,
+                (_, columns) => columns.is_some(),
+            }
+        }
+
+        /// Find the position of the first value equal to `needle`, searching line by line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.find("8"), Some((3, 2)));
+        /// assert_eq!(reader.find("11"), None);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// Then you will receive the line and row of the value, which can be used by [read_value].
+        ///
+        /// [read_value]: Reader::read_value
+        pub fn find(&self, needle: &str) -> Option<(usize, usize)> {
+            self.cells()
+                .find(|(_, _, value)| self.file.trimmed(value) == needle)
+                .map(|(line, row, _)| (line, row))
+        }
+
+        /// Find the positions of all the values equal to `needle`, in the order of lines and rows.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.find_all("1"), vec![(1, 1)]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn find_all(&self, needle: &str) -> Vec<(usize, usize)> {
+            self.cells()
+                .filter(|(_, _, value)| self.file.trimmed(value) == needle)
+                .map(|(line, row, _)| (line, row))
+                .collect()
+        }
+
+        /// Check if the text of the file contains `needle`, which may be any part of a line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// assert!(reader.contains("4,5"));
+        /// assert!(!reader.contains("4,6"));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn contains(&self, needle: &str) -> bool {
+            self.lines.contains(needle)
+        }
+
+        /// Find the lines whose text matches the `predicate`, and return their line numbers starting
+        /// from 1.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let reader = file.reader()?;
+        ///
+        /// let lines = reader.matching_lines(|l| l.starts_with('1'));
+        ///
+        /// assert_eq!(lines, vec![1, 4]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// Then you will receive the line numbers, which can be used by [read_line].
+        ///
+        /// [read_line]: Reader::read_line
+        pub fn matching_lines<F: Fn(&str) -> bool>(&self, predicate: F) -> Vec<usize> {
+            self.iter_lines()
+                .enumerate()
+                .filter(|(_, l)| predicate(l))
+                .map(|(i, _)| i + 1)
+                .collect()
+        }
+
+        /// Read the values of every line by the capture groups of a regular expression, for the lines
+        /// which cannot be divided by a split character, such as logs. The lines which do not match the
+        /// pattern are skipped. A group which does not take part in the match is read as an empty value,
+        /// and the whole match is the only value if the pattern has no groups.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "12:00:01 [INFO]  started\n12:00:05 [ERROR] failed".
+        /// let file = FileAPI::from("server.log");
+        /// let reader = file.reader()?;
+        ///
+        /// let entries = reader.read_regex(r"^(\S+) \[(\w+)\]\s+(.*)$")?;
+        ///
+        /// assert_eq!(entries[1], vec!["12:00:05", "ERROR", "failed"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::InvalidPattern] if the pattern is not a valid regular expression.
+        #[cfg(feature = "regex")]
+        pub fn read_regex(&self, pattern: &str) -> Result<Vec<Vec<String>>, FileError> {
+            let regex = Regex::new(pattern).map_err(|e| FileError::InvalidPattern(e.to_string()))?;
+            Ok(self.iter_lines()
+                .filter_map(|l| regex.captures(l))
+                .map(|captures| {
+                    let groups = if captures.len() > 1 { 1..captures.len() } else { 0..1 };
+                    groups.map(|i| captures.get(i).map_or("", |m| m.as_str()).to_string()).collect()
+                })
+                .collect())
+        }
+
+        // iterate over all the values with their line and row.
+        fn cells(&self) -> impl Iterator<Item = (usize, usize, String)> + '_ {
+            self.iter_lines().enumerate().flat_map(move |(i, l)| {
+                self.file.split_line(l)
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(j, value)| (i + 1, j + 1, value))
+            })
+        }
+
+        /// A function to read the specific row in this csv file. The values are divided by the configured
+        /// split character, so remember to set it with `split(',')` for a comma separated file.
+        ///
+        /// If `has_header` is true, the first line is skipped. This is the same as [read_column].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // read the specific row of the csv file, skipping the header.
+        /// let row = FileAPI::from("filename.gph").split(',').reader()?.read_csv::<usize>(1, true)?;
+        ///
+        /// assert_eq!(row, vec![4, 7, 10]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// Then you will receive a [Vec] recording the data in this row, which are also parsed to [usize] type.
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn read_csv<T: FromStr>(&self, row: usize, has_header: bool) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.read_column(row, has_header)
+        }
+
+        /// A function to read the specific row of all the lines, using the configured split character.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // read the second row of every line.
+        /// let row = FileAPI::from("filename.gph").split(',').reader()?.read_column::<usize>(2, false)?;
+        ///
+        /// assert_eq!(row, vec![2, 5, 8, 12]);
+        ///
+        /// // skip the first line as a header.
+        /// let row = FileAPI::from("filename.gph").split(',').reader()?.read_column::<usize>(2, true)?;
+        ///
+        /// assert_eq!(row, vec![5, 8, 12]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        /// Then you will receive a [Vec] recording the data in this row, which are also parsed to [usize] type.
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        pub fn read_column<T: FromStr>(&self, row: usize, skip_header: bool) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            self.data_lines()
+                .skip(skip_header as usize)
+                .map(|(line, l)| {
+                    let value = row.checked_sub(1)
+                        .and_then(|j| self.file.split_line(l).into_iter().nth(j))
+                        .ok_or(FileError::IndexOutOfBounds { line, row })?;
+                    self.file.parse_cell::<T>(&value).map_err(|e| e.at(line, row))
+                })
+                .collect()
+        }
+
+        /// A function to read several rows of all the lines like [read_column], but in one pass over the
+        /// lines. The n-th column of the result is the n-th of `rows`.
+        ///
+        /// If `skip_header` is true, the first line is skipped.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// // read the 2th and 1th rows, below the first line.
+        /// let columns = reader.read_columns::<usize>(&[2, 1], true)?;
+        ///
+        /// assert_eq!(columns, vec![vec![5, 8, 12], vec![4, 7, 10]]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in one of the rows, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn read_columns<T: FromStr>(&self, rows: &[usize], skip_header: bool) -> Result<Vec<Vec<T>>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let mut columns: Vec<Vec<T>> = rows.iter().map(|_| Vec::new()).collect();
+            for (line, l) in self.data_lines().skip(skip_header as usize) {
+                let values = self.file.split_line(l);
+                for (column, &row) in columns.iter_mut().zip(rows) {
+                    let value = row.checked_sub(1)
+                        .and_then(|j| values.get(j))
+                        .ok_or(FileError::IndexOutOfBounds { line, row })?;
+                    column.push(self.file.parse_cell::<T>(value).map_err(|e| e.at(line, row))?);
+                }
+            }
+            Ok(columns)
+        }
+
+        /// A function to read the specific row of all the lines like [read_column], but a value which is
+        /// empty, missing, or cannot be parsed into `T` is read as [None], such as a sparse column.
+        ///
+        /// If `skip_header` is true, the first line is skipped.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "id,score\n1,90\n2,\n3,n/a\n4".
+        /// let file = FileAPI::from("scores.csv").split(',');
+        /// let scores = file.reader()?.read_column_opt::<usize>(2, true);
+        ///
+        /// assert_eq!(scores, vec![Some(90), None, None, None]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn read_column_opt<T: FromStr>(&self, row: usize, skip_header: bool) -> Vec<Option<T>> {
+            self.data_lines()
+                .skip(skip_header as usize)
+                .map(|(_, l)| {
+                    row.checked_sub(1)
+                        .and_then(|j| self.file.split_line(l).into_iter().nth(j))
+                        .filter(|value| !value.trim().is_empty())
+                        .and_then(|value| self.file.trimmed(&value).parse::<T>().ok())
+                })
+                .collect()
+        }
+
+        /// A function to read the specific row of all the lines like [read_column], but into a set, for
+        /// checking quickly whether a value is in the column. The same values are kept only once.
+        ///
+        /// If `skip_header` is true, the first line is skipped, so the name of the column is not in the
+        /// set.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "id,name\n3,Alice\n7,Bob\n3,Carol".
+        /// let file = FileAPI::from("people.csv").split(',');
+        /// let ids = file.reader()?.read_column_set::<usize>(1, true)?;
+        ///
+        /// assert_eq!(ids.len(), 2);
+        /// assert!(ids.contains(&7));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn read_column_set<T: FromStr + Eq + Hash>(&self, row: usize, skip_header: bool) -> Result<HashSet<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            Ok(self.read_column::<T>(row, skip_header)?.into_iter().collect())
+        }
+
+        /// Count how many times each value appears in the specific row of all the lines, which are read
+        /// like [read_column].
+        ///
+        /// If `skip_header` is true, the first line is skipped, so the name of the column is not counted.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "name,city\nAlice,Paris\nBob,Rome\nCarol,Paris".
+        /// let file = FileAPI::from("people.csv").split(',');
+        /// let counts = file.reader()?.value_counts::<String>(2, true)?;
+        ///
+        /// assert_eq!(counts["Paris"], 2);
+        /// assert_eq!(counts["Rome"], 1);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn value_counts<T: FromStr + Eq + Hash>(&self, row: usize, skip_header: bool) -> Result<HashMap<T, usize>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let mut counts = HashMap::new();
+            for value in self.read_column::<T>(row, skip_header)? {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+            Ok(counts)
+        }
+
+        /// Add up the values in the specific row of all the lines, which are read like [read_column]. The
+        /// sum of an empty column is zero.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.sum::<usize>(2, true)?, 25);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn sum<T: FromStr + Sum>(&self, row: usize, skip_header: bool) -> Result<T, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            Ok(self.read_column::<T>(row, skip_header)?.into_iter().sum())
+        }
+
+        /// Get the average of the values in the specific row of all the lines, which are read like
+        /// [read_column]. The mean of an empty column is NaN.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.mean(1, false)?, 5.5);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value is not a number.
+        ///
+        /// [read_column]: Reader::read_column
+        pub fn mean(&self, row: usize, skip_header: bool) -> Result<f64, FileError> {
+            let values = self.read_column::<f64>(row, skip_header)?;
+            Ok(values.iter().sum::<f64>() / values.len() as f64)
+        }
+
+        /// Find the largest value in the specific row of all the lines, with the line where it is. The
+        /// values which are missing or cannot be parsed into `T`, such as a header, are skipped, and the
+        /// first line is returned if the largest value is found in several lines. Returns [None] if there
+        /// is no value to compare.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.column_max::<usize>(2), Some((4, 12)));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn column_max<T: FromStr + PartialOrd>(&self, row: usize) -> Option<(usize, T)> {
+            self.column_extreme(row, Ordering::Greater)
+        }
+
+        /// Find the smallest value in the specific row of all the lines, with the line where it is, like
+        /// [column_max].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let reader = file.reader()?;
+        ///
+        /// assert_eq!(reader.column_min::<usize>(3), Some((1, 3)));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [column_max]: Reader::column_max
+        pub fn column_min<T: FromStr + PartialOrd>(&self, row: usize) -> Option<(usize, T)> {
+            self.column_extreme(row, Ordering::Less)
+        }
+
+        // find the first value in the row which is not ordered before any other value by `wanted`.
+        fn column_extreme<T: FromStr + PartialOrd>(&self, row: usize, wanted: Ordering) -> Option<(usize, T)> {
+            let j = row.checked_sub(1)?;
+            let mut extreme: Option<(usize, T)> = None;
+            for (line, l) in self.data_lines() {
+                let values = self.file.split_line(l);
+                let Some(value) = values.get(j).and_then(|v| self.file.trimmed(v).parse::<T>().ok()) else {
+                    continue;
+                };
+                if extreme.as_ref().is_none_or(|(_, e)| value.partial_cmp(e) == Some(wanted)) {
+                    extreme = Some((line, value));
+                }
+            }
+            extreme
+        }
+
+        /// Read all the lines into a [HashMap], which maps the value in the `key_row` of every line to the
+        /// value in its `value_row`, such as a lookup table. If a key is found in several lines, the value
+        /// of the last line is kept.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// // the file is "apple,3\nbanana,5\napple,4".
+        /// let file = FileAPI::from("stock.csv").split(',');
+        /// let stock = file.reader()?.read_map::<String, usize>(1, 2)?;
+        ///
+        /// assert_eq!(stock["apple"], 4);
+        /// assert_eq!(stock["banana"], 5);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in one of the rows, or
+        /// [FileError::Parse] if a value cannot be parsed into `K` or `V`.
+        pub fn read_map<K: FromStr + Eq + Hash, V: FromStr>(&self, key_row: usize, value_row: usize) -> Result<HashMap<K, V>, FileError>
+            where
+                <K as FromStr>::Err: Debug,
+                <V as FromStr>::Err: Debug,
+        {
+            let mut map = HashMap::new();
+            for (line, l) in self.data_lines() {
+                let values = self.file.split_line(l);
+                let get = |row: usize| row.checked_sub(1)
+                    .and_then(|j| values.get(j))
+                    .ok_or(FileError::IndexOutOfBounds { line, row });
+                let key = self.file.parse_cell::<K>(get(key_row)?).map_err(|e| e.at(line, key_row))?;
+                let value = self.file.parse_cell::<V>(get(value_row)?).map_err(|e| e.at(line, value_row))?;
+                map.insert(key, value);
+            }
+            Ok(map)
+        }
+
+        /// Read the lines into records, such as structs, tuples or [Vec]s, whose fields are given the
+        /// values of a line in order. The first `skip_header` lines are skipped.
+        ///
+        /// The values are parsed into the types of the fields. An empty value is read as [None] for
+        /// an [Option] field, and a value is read as a unit variant for an enum field.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        /// use serde::Deserialize;
+        ///
+        /// #[derive(Deserialize)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        ///     email: Option<String>,
+        /// }
+        ///
+        /// // the file is "name,age,email\nAlice,30,\nBob,25,bob@example.com".
+        /// let file = FileAPI::from("people.csv").split(',');
+        /// let people = file.reader()?.read_records::<Person>(1)?;
+        ///
+        /// assert_eq!(people[0].name, "Alice");
+        /// assert_eq!(people[1].email.as_deref(), Some("bob@example.com"));
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::RaggedRow] if a line has a different number of values from the fields of
+        /// a struct or tuple, or [FileError::Parse] if a value cannot be parsed into its field.
+        #[cfg(feature = "serde")]
+        pub fn read_records<R: DeserializeOwned>(&self, skip_header: usize) -> Result<Vec<R>, FileError> {
+            self.data_lines()
+                .skip(skip_header)
+                .map(|(line, l)| R::deserialize(RowDeserializer::new(self.file, line, l)).map_err(|e| e.at(line, 0)))
+                .collect()
+        }
+
+        /// Read the file as JSON Lines, where every line is a JSON value, such as an object. The blank
+        /// lines are skipped.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        /// use serde::Deserialize;
+        ///
+        /// #[derive(Deserialize)]
+        /// struct Event {
+        ///     id: u32,
+        ///     kind: String,
+        /// }
+        ///
+        /// // the file is "{\"id\": 1, \"kind\": \"start\"}\n{\"id\": 2, \"kind\": \"stop\"}".
+        /// let file = FileAPI::from("events.jsonl");
+        /// let events = file.reader()?.read_jsonl::<Event>()?;
+        ///
+        /// assert_eq!(events[1].kind, "stop");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Parse] if a line is not valid JSON or cannot be deserialized into `T`.
+        #[cfg(feature = "serde_json")]
+        pub fn read_jsonl<T: DeserializeOwned>(&self) -> Result<Vec<T>, FileError> {
+            self.iter_lines()
+                .enumerate()
+                .filter(|(_, l)| !l.trim().is_empty())
+                .map(|(i, l)| serde_json::from_str(l).map_err(|e| FileError::Parse { line: i + 1, row: 0, value: l.to_string(), message: e.to_string() }))
+                .collect()
+        }
+    }
+
+    /// A reader structure reading the values of a file mapped into memory, which is created by
+    /// [FileAPI::mmap_reader].
+    ///
+    /// The file is not loaded. The lines are found the first time that a line is read, and only the
+    /// lines read are decoded, so reading a few values of a large file is fast and needs little
+    /// memory. The file should be encoded in UTF-8, whose lines are ended by `\n`, `\r\n` or `\r`.
+    ///
+    /// The content is read from the file every time, so the file should not be modified while the
+    /// [MmapReader] exists.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph").split(',');
+    /// let reader = file.mmap_reader()?;
+    ///
+    /// assert_eq!(reader.count_lines(), 4);
+    /// assert_eq!(reader.read_line::<usize>(2)?, vec![4, 5, 6]);
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    #[cfg(feature = "memmap")]
+    pub struct MmapReader<'a> {
+        map: Option<Mmap>,
+        file: &'a FileAPI,
+        lines: OnceCell<Vec<(usize, usize)>>
+    }
+
+    #[cfg(feature = "memmap")]
+    impl MmapReader<'_> {
+        fn from(file: &FileAPI) -> Result<MmapReader<'_>, FileError> {
+            file.require_utf8()?;
+            let f = File::open(&file.path)?;
+            // an empty file cannot be mapped on some platforms.
+            let map = if f.metadata()?.len() == 0 {
+                None
+            } else {
+                // SAFETY: the file is only read, and the documentation of MmapReader requires that it
+                // is not modified while it is mapped.
+                Some(unsafe { Mmap::map(&f)? })
+            };
+            Ok(MmapReader { map, file, lines: OnceCell::new() })
+        }
+
+        // the mapped bytes of the file.
+        fn bytes(&self) -> &[u8] {
+            self.map.as_deref().unwrap_or_default()
+        }
+
+        // the start and end of every line, without the line ending.
+        fn line_bounds(&self) -> &Vec<(usize, usize)> {
+            self.lines.get_or_init(|| {
+                let cr = ends_lines_by_cr(self.bytes()).unwrap_or_default();
+                line_bounds(self.bytes(), self.file.line_quote(), cr)
+            })
+        }
+
+        /// Get the text of a line without dividing or parsing it.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// assert_eq!(file.mmap_reader()?.line(1)?, "1,2,3");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line, or
+        /// [FileError::InvalidEncoding] if the line is not valid UTF-8.
+        pub fn line(&self, line: usize) -> Result<&str, FileError> {
+            let (start, end) = line.checked_sub(1)
+                .and_then(|i| self.line_bounds().get(i))
+                .copied()
+                .ok_or(FileError::IndexOutOfBounds { line, row: 0 })?;
+            let text = std::str::from_utf8(&self.bytes()[start..end])
+                .map_err(|e| FileError::InvalidEncoding { byte_offset: start + e.valid_up_to() })?;
+            Ok(if line == 1 { text.strip_prefix(BOM).unwrap_or(text) } else { text })
+        }
+
+        /// Count the lines in the file.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// assert_eq!(file.mmap_reader()?.count_lines(), 4);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn count_lines(&self) -> usize {
+            self.line_bounds().len()
+        }
+
+        /// Read a line and parse it into a certain type, like [Reader::read_line].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let line = file.mmap_reader()?.read_line::<usize>(2)?;
+        ///
+        /// assert_eq!(line, vec![4, 5, 6]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line,
+        /// [FileError::InvalidEncoding] if the line is not valid UTF-8, or [FileError::Parse] if a value
+        /// cannot be parsed into `T`.
+        pub fn read_line<T: FromStr>(&self, line: usize) -> Result<Vec<T>, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            Reader::read_line_parse(line, self.line(line)?, self.file)
+        }
+
+        /// Read a single value and parse it into a certain type, like [Reader::get].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let value = file.mmap_reader()?.get::<usize>(2, 3)?;
+        ///
+        /// assert_eq!(value, 6);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if there is no value at this position,
+        /// [FileError::InvalidEncoding] if the line is not valid UTF-8, or [FileError::Parse] if the
+        /// value cannot be parsed into `T`.
+        pub fn get<T: FromStr>(&self, line: usize, row: usize) -> Result<T, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let values = self.file.split_line(self.line(line)?);
+            let value = row.checked_sub(1)
+                .and_then(|j| values.get(j))
+                .ok_or(FileError::IndexOutOfBounds { line, row })?;
+            self.file.parse_cell::<T>(value).map_err(|e| e.at(line, row))
+        }
+    }
+
+    /// A changer structure for change some specific values in the file in succession.
+    ///
+    /// # Example
+    /// collect a [Changer] type (same with [Builder], [Reader]):
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph");
+    /// let changer = file.changer()?;
+    ///
+    /// // change the value in the 1th line and 2th row to value '123':
+    /// // you can consecutive change values:
+    /// changer.change_value(1, 2, "234")?
+    ///     .change_value(2, 2, "567")?
+    ///     .change_value(3, 2, "560")?
+    ///     .execute()?; // after modifying the value, you will need to execute your changes.
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    pub struct Changer<'a> {
+        lines: Vec<String>,
+        file: &'a FileAPI,
+        trailing_newline: bool,
+        line_ending: LineEnding,
+        bom: bool,
+        backup: bool,
+        // the lock file held by a locked changer, which is unlocked when it is closed.
+        _lock: Option<File>
+    }
+
+    impl Changer<'_> {
+        fn from(file: &FileAPI, lock: Option<File>) -> Result<Changer<'_>, FileError> {
+            let (mut lines, line_ending) = file.load(false)?;
+            // the byte order mark is kept out of the first value, and written back on execute.
+            let bom = strip_bom(&mut lines);
+            Ok(Changer::with_lines(file, &lines, line_ending, bom, lock))
+        }
+
+        fn with_lines<'b>(file: &'b FileAPI, text: &str, line_ending: LineEnding, bom: bool, lock: Option<File>) -> Changer<'b> {
+            let trailing_newline = text.ends_with('\n');
+            let lines = line_bounds(text.as_bytes(), file.line_quote(), false).into_iter()
+                .map(|(start, end)| text[start..end].to_string())
+                .collect();
+            Changer { lines, file, trailing_newline, line_ending: file.line_ending.unwrap_or(line_ending), bom, backup: false, _lock: lock }
+        }
+
+        // write the file by the given function like [FileAPI::write_with], after copying the file to the
+        // backup file if it is set.
+        fn write_with<F: FnOnce(&mut File) -> io::Result<()>>(&self, write: F) -> Result<(), FileError> {
+            if self.backup {
+                copy(&self.file.path, format!("{}.bak", self.file.path))?;
+            }
+            self.file.write_with(write)
+        }
+
+        /// Copy the file to a backup file before writing the changes, whose name is the file name
+        /// followed by `.bak`, so that the changes can be undone. An old backup file is replaced. The
+        /// default is false.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the old content is kept in "filename.gph.bak".
+        /// file.changer()?
+        ///     .backup(true)
+        ///     .delete_line(1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn backup(mut self, backup: bool) -> Self {
+            self.backup = backup;
+            self
+        }
+
+        /// A function to change a value in this data storage file.
+        ///
+        /// # Example
+        /// collect a [Changer] type (same with [Builder], [Reader]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let changer = file.changer()?;
+        ///
+        /// // change the value in the 1th line and 2th row to value '123':
+        /// // you can consecutive change values:
+        /// changer.change_value(1, 2, "234")?
+        ///     .change_value(2, 2, "567")?
+        ///     .change_value(3, 2, "560")?
+        ///     .execute()?; // after modifying the value, you will need to execute your changes.
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at this position, including
+        /// when `line` or `row` is 0.
+        pub fn change_value(mut self, line: usize, row: usize, value: &str) -> Result<Self, FileError> {
+            let i = self.line_index(line).map_err(|_| FileError::IndexOutOfBounds { line, row })?;
+            let mut a_line = self.file.split_line_all(&self.lines[i]);
+            let cell = row.checked_sub(1)
+                .and_then(|j| a_line.get_mut(j))
+                .ok_or(FileError::IndexOutOfBounds { line, row })?;
+            *cell = value.to_string();
+            self.lines[i] =  self.file.join_line(&a_line);
+            Ok(self)
+        }
+
+        /// A function to add a number to a value in this data storage file, such as a counter.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("counters.csv").split(',');
+        ///
+        /// // count a new visit in the 2th row of the 1th line.
+        /// file.changer()?
+        ///     .increment(1, 2, 1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at this position, or
+        /// [FileError::Parse] if the value cannot be parsed into `T`.
+        pub fn increment<T: FromStr + Add<Output = T> + ToString>(self, line: usize, row: usize, by: T) -> Result<Self, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let value = line.checked_sub(1)
+                .and_then(|i| self.lines.get(i))
+                .zip(row.checked_sub(1))
+                .and_then(|(l, j)| self.file.split_line(l).into_iter().nth(j))
+                .ok_or(FileError::IndexOutOfBounds { line, row })?;
+            let value = self.file.parse_cell::<T>(&value).map_err(|e| e.at(line, row))? + by;
+            self.change_value(line, row, &value.to_string())
+        }
+
+        /// A function to insert a new line at the given position. The following lines are moved down,
+        /// and inserting at the position after the last line appends the new line to the file.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// // insert a new line as the 2th line, and another one at the end of the 4 lines file.
+        /// file.changer()?
+        ///     .insert_line(2, "1,1,1")?
+        ///     .insert_line(6, "9,9,9")?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if `line` is 0 or more than one line past the end.
+        pub fn insert_line(mut self, line: usize, content: &str) -> Result<Self, FileError> {
+            if line < 1 || line > self.lines.len() + 1 {
+                return Err(FileError::IndexOutOfBounds { line, row: 0 });
+            }
+            self.lines.insert(line - 1, content.to_string());
+            Ok(self)
+        }
+
+        /// A function to delete a line. The following lines are moved up.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// // delete the 2th line.
+        /// file.changer()?
+        ///     .delete_line(2)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn delete_line(mut self, line: usize) -> Result<Self, FileError> {
+            let i = self.line_index(line)?;
+            self.lines.remove(i);
+            Ok(self)
+        }
+
+        /// A function to add a new value at the end of a line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the 4th line "10,12" will be "10,12,14".
+        /// file.changer()?
+        ///     .append_value(4, "14")?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn append_value(mut self, line: usize, value: &str) -> Result<Self, FileError> {
+            let i = self.line_index(line)?;
+            let mut values = self.values_of(i);
+            values.push(value.to_string());
+            self.lines[i] = self.file.join_line(&values);
+            Ok(self)
+        }
+
+        /// A function to add a new value at the start of a line.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the 4th line "10,12" will be "8,10,12".
+        /// file.changer()?
+        ///     .prepend_value(4, "8")?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn prepend_value(mut self, line: usize, value: &str) -> Result<Self, FileError> {
+            let i = self.line_index(line)?;
+            let mut values = self.values_of(i);
+            values.insert(0, value.to_string());
+            self.lines[i] = self.file.join_line(&values);
+            Ok(self)
+        }
+
+        /// A function to swap two lines.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// // swap the 2th and 3th lines.
+        /// file.changer()?
+        ///     .swap_lines(2, 3)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn swap_lines(mut self, a: usize, b: usize) -> Result<Self, FileError> {
+            let i = self.line_index(a)?;
+            let j = self.line_index(b)?;
+            self.lines.swap(i, j);
+            Ok(self)
+        }
+
+        /// A function to swap two values, which may be in the same line or in different lines.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // swap the value in the 1th line and 1th row with the one in the 3th line and 2th row.
+        /// file.changer()?
+        ///     .swap_values(1, 1, 3, 2)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no value at either position.
+        pub fn swap_values(mut self, line1: usize, row1: usize, line2: usize, row2: usize) -> Result<Self, FileError> {
+            let i1 = self.line_index(line1).map_err(|_| FileError::IndexOutOfBounds { line: line1, row: row1 })?;
+            let i2 = self.line_index(line2).map_err(|_| FileError::IndexOutOfBounds { line: line2, row: row2 })?;
+            let mut values1 = self.file.split_line_all(&self.lines[i1]);
+            let j1 = row1.checked_sub(1)
+                .filter(|&j| j < values1.len())
+                .ok_or(FileError::IndexOutOfBounds { line: line1, row: row1 })?;
+            if i1 == i2 {
+                let j2 = row2.checked_sub(1)
+                    .filter(|&j| j < values1.len())
+                    .ok_or(FileError::IndexOutOfBounds { line: line2, row: row2 })?;
+                values1.swap(j1, j2);
+                self.lines[i1] = self.file.join_line(&values1);
+                return Ok(self);
+            }
+            let mut values2 = self.file.split_line_all(&self.lines[i2]);
+            let j2 = row2.checked_sub(1)
+                .filter(|&j| j < values2.len())
+                .ok_or(FileError::IndexOutOfBounds { line: line2, row: row2 })?;
+            std::mem::swap(&mut values1[j1], &mut values2[j2]);
+            self.lines[i1] = self.file.join_line(&values1);
+            self.lines[i2] = self.file.join_line(&values2);
+            Ok(self)
+        }
+
+        /// A function to sort the lines by the values in a row, which are parsed into a certain type.
+        /// The first `skip_header` lines are kept at the top, and the lines with equal values keep their
+        /// order. The comment lines, and the blank lines if they are skipped, are kept in their places.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("prices.csv").split(',');
+        ///
+        /// // sort the lines by the price in the 2th row, below the header line.
+        /// file.changer()?
+        ///     .sort_by_column::<usize>(2, 1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// The skipped lines are not parsed:
+        /// ```
+        /// use self::simple_file_manager::fileapi::{FileAPI, Reader};
+        ///
+        /// let file = FileAPI::from("").split(',').skip_blank_lines(true);
+        /// let changer = Reader::from_string(&file, "3,x\n\n1,y\n2,z").into_changer();
+        ///
+        /// assert_eq!(changer.sort_by_column::<usize>(1, 0)?.preview(), "1,y\n\n2,z\n3,x");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row, or
+        /// [FileError::Parse] if a value cannot be parsed into `T`.
+        pub fn sort_by_column<T: Ord + FromStr>(mut self, row: usize, skip_header: usize) -> Result<Self, FileError>
+            where
+                <T as FromStr>::Err: Debug,
+        {
+            let file = self.file;
+            // the indexes of the lines which are sorted, between which the skipped lines stay.
+            let slots = (skip_header.min(self.lines.len())..self.lines.len())
+                .filter(|&i| !file.is_skipped(&self.lines[i]))
+                .collect::<Vec<usize>>();
+            let mut keys = slots.iter()
+                .map(|&i| {
+                    let value = row.checked_sub(1)
+                        .and_then(|j| file.split_line(&self.lines[i]).into_iter().nth(j))
+                        .ok_or(FileError::IndexOutOfBounds { line: i + 1, row })?;
+                    Ok((file.parse_cell::<T>(&value).map_err(|e| e.at(i + 1, row))?, i))
+                })
+                .collect::<Result<Vec<(T, usize)>, FileError>>()?;
+            keys.sort_by(|a, b| a.0.cmp(&b.0));
+            let sorted = keys.iter().map(|&(_, i)| self.lines[i].clone()).collect::<Vec<String>>();
+            for (i, line) in slots.into_iter().zip(sorted) {
+                self.lines[i] = line;
+            }
+            Ok(self)
+        }
+
+        /// A function to keep only the lines whose values satisfy the predicate. The first `skip_header`
+        /// lines, the comment lines, and the blank lines if they are skipped, are always kept.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("prices.csv").split(',');
+        ///
+        /// // drop the lines with a negative price in the 2th row, below the header line.
+        /// file.changer()?
+        ///     .retain(|values| !values[1].starts_with('-'), 1)
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn retain<F: Fn(&[&str]) -> bool>(mut self, predicate: F, skip_header: usize) -> Self {
+            let file = self.file;
+            let mut i = 0;
+            self.lines.retain(|l| {
+                i += 1;
+                if i <= skip_header || file.is_skipped(l) {
+                    return true;
+                }
+                let values = file.split_line(l);
+                let values = values.iter().map(|v| file.trimmed(v)).collect::<Vec<&str>>();
+                predicate(&values)
+            });
+            self
+        }
+
+        /// A function to transform the values in a row of every line by a function. The first
+        /// `skip_header` lines, the comment lines, and the blank lines if they are skipped, are kept
+        /// unchanged.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("prices.csv").split(',');
+        ///
+        /// // convert the price in the 2th row to cents, below the header line.
+        /// file.changer()?
+        ///     .map_column(2, |v| (v.trim().parse::<f64>().unwrap() * 100.0).to_string(), 1)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if a line has no value in this row.
+        pub fn map_column<F: Fn(&str) -> String>(mut self, row: usize, f: F, skip_header: usize) -> Result<Self, FileError> {
+            for i in skip_header..self.lines.len() {
+                if self.file.is_skipped(&self.lines[i]) {
+                    continue;
+                }
+                let mut values = self.file.split_line_all(&self.lines[i]);
+                let value = row.checked_sub(1)
+                    .and_then(|j| values.get_mut(j))
+                    .ok_or(FileError::IndexOutOfBounds { line: i + 1, row })?;
+                *value = f(value);
+                self.lines[i] = self.file.join_line(&values);
+            }
+            Ok(self)
+        }
+
+        /// A function to replace the values of a line by a function, which takes the values of the line
+        /// and returns the new values. The new values may be more or fewer than the old ones.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // "4,5,6" in the 2th line will be "6,5,4,15".
+        /// file.changer()?
+        ///     .map_row(2, |mut values| {
+        ///         values.reverse();
+        ///         values.push("15".to_string());
+        ///         values
+        ///     })?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::IndexOutOfBounds] if the file has no such line.
+        pub fn map_row<F: FnOnce(Vec<String>) -> Vec<String>>(mut self, line: usize, f: F) -> Result<Self, FileError> {
+            let i = self.line_index(line)?;
+            let values = f(self.file.split_line_all(&self.lines[i]));
+            self.lines[i] = self.file.join_line(&values);
+            Ok(self)
+        }
+
+        /// A function to transform all the values of every line by a function. The first `skip_header`
+        /// lines, the comment lines, and the blank lines if they are skipped, are kept unchanged.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("names.csv").split(',');
+        ///
+        /// // uppercase all the values.
+        /// file.changer()?
+        ///     .map_all(|v| v.to_uppercase(), 0)
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn map_all<F: Fn(&str) -> String>(mut self, f: F, skip_header: usize) -> Self {
+            for i in skip_header..self.lines.len() {
+                if self.file.is_skipped(&self.lines[i]) {
+                    continue;
+                }
+                let values = self.file.split_line_all(&self.lines[i])
+                    .iter()
+                    .map(|v| f(v))
+                    .collect::<Vec<String>>();
+                self.lines[i] = self.file.join_line(&values);
+            }
+            self
+        }
+
+        /// A function to add the `fill` value to the end of the lines with less values than the longest
+        /// line, so that all the lines have the same number of values. The comment lines, and the blank
+        /// lines if they are skipped, are kept unchanged.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the last line "10,12" will be "10,12,0".
+        /// file.changer()?
+        ///     .pad_rows("0")
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn pad_rows(mut self, fill: &str) -> Self {
+            let file = self.file;
+            let len = self.lines.iter()
+                .filter(|l| !file.is_skipped(l))
+                .map(|l| file.split_line(l).len())
+                .max()
+                .unwrap_or(0);
+            for line in self.lines.iter_mut().filter(|l| !file.is_skipped(l)) {
+                let mut values = file.split_line(line);
+                if values.len() < len {
+                    values.resize(len, fill.to_string());
+                    *line = file.join_line(&values);
+                }
+            }
+            self
+        }
+
+        /// A function to remove the values after the first `len` values of every line, the counterpart of
+        /// [pad_rows]. The comment lines, and the blank lines if they are skipped, are kept unchanged.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // "1,2,3" will be "1,2".
+        /// file.changer()?
+        ///     .truncate_rows(2)
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len` is 0.
+        ///
+        /// [pad_rows]: Changer::pad_rows
+        pub fn truncate_rows(mut self, len: usize) -> Self {
+            if len < 1 {
+                panic!("The 'len' parameter should not less than 1.")
+            }
+            let file = self.file;
+            for line in self.lines.iter_mut().filter(|l| !file.is_skipped(l)) {
+                let mut values = file.split_line_all(line);
+                if values.len() > len {
+                    values.truncate(len);
+                    *line = file.join_line(&values);
+                }
+            }
+            self
+        }
+
+        /// A function to divide every line by the split character and join the values again by the
+        /// `split` character, such as to convert a space-separated file into a comma-separated file. A
+        /// fixed-width file set by [FileAPI::fixed_widths] is converted too, with the characters after the
+        /// last column as the last value. The values are trimmed if trim is on, the default, and the
+        /// comment lines, and the blank lines if they are skipped, are kept unchanged.
+        ///
+        /// In csv mode, a value containing the new split character is quoted, otherwise it is written as
+        /// it is and cannot be read back as one value. The other functions of this [Changer] still use
+        /// the old split character, so call it after them.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").csv_mode(true);
+        ///
+        /// // "Smith,John 42" will be "\"Smith,John\",42".
+        /// file.changer()?
+        ///     .reformat(',')
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn reformat(mut self, split: char) -> Self {
+            let target = FileAPI { split: split.to_string(), widths: None, ..self.file.clone() };
+            let file = self.file;
+            for line in self.lines.iter_mut().filter(|l| !file.is_skipped(l)) {
+                let values = file.split_line_all(line);
+                let values = values.iter().map(|v| file.trimmed(v)).collect::<Vec<&str>>();
+                *line = target.join_line(&values);
+            }
+            self
+        }
+
+        /// A function to flip the lines and rows of the file, so that the n-th line becomes the n-th row.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("matrix.txt").split(',');
+        ///
+        /// // "1,2,3\n4,5,6" will be "1,4\n2,5\n3,6".
+        /// file.changer()?
+        ///     .transpose()?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::RaggedRow] if the lines have different numbers of values.
+        pub fn transpose(mut self) -> Result<Self, FileError> {
+            let grid = self.lines.iter().map(|l| self.file.split_line_all(l)).collect::<Vec<Vec<String>>>();
+            if let Some(expected) = grid.first().map(|l| l.len()) {
+                if let Some(i) = grid.iter().position(|l| l.len() != expected) {
+                    return Err(FileError::RaggedRow { line: i + 1, expected, found: grid[i].len() });
+                }
+            }
+            self.lines = transpose(grid).iter().map(|l| self.file.join_line(l)).collect();
+            Ok(self)
+        }
+
+        // get the index of a line in the lines, or an error if there is no such line.
+        fn line_index(&self, line: usize) -> Result<usize, FileError> {
+            line.checked_sub(1)
+                .filter(|&i| i < self.lines.len())
+                .ok_or(FileError::IndexOutOfBounds { line, row: 0 })
+        }
+
+        // divide a line into values. An empty line has no values.
+        fn values_of(&self, i: usize) -> Vec<String> {
+            if self.lines[i].is_empty() {
+                Vec::new()
+            } else {
+                self.file.split_line_all(&self.lines[i])
+            }
+        }
+
+        /// Get the content which [execute] would write to the file, without writing it.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let changer = file.changer()?.change_value(1, 1, "100")?;
+        ///
+        /// assert_eq!(changer.preview(), "100,2,3\n4,5,6\n7,8,9\n10,12");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [execute]: Changer::execute
+        pub fn preview(&self) -> String {
+            let text = join_lines(&self.lines, self.line_ending, self.trailing_newline);
+            if self.bom { format!("{}{}", BOM, text) } else { text }
+        }
+
+        /// Confirm and implement the changes. The file ends with a newline only if it did when the
+        /// [Changer] was created, so the unchanged lines are kept byte for byte.
+        ///
+        /// # Example
+        /// collect a [Changer] type (same with [Builder], [Reader]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let changer = file.changer()?;
+        ///
+        /// // change the value in the 1th line and 2th row to value '123':
+        /// // you can consecutive change values:
+        /// changer.change_value(1, 2, "234")?
+        ///     .change_value(2, 2, "567")?
+        ///     .change_value(3, 2, "560")?
+        ///     .execute()?; // after modifying the value, you will need to execute your changes.
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be created or written, or the [backup] file cannot be
+        /// written.
+        ///
+        /// [backup]: Changer::backup
+        pub fn execute(&self) -> Result<&FileAPI, FileError> {
+            self.write_with(|file| self.file.write_text(file, &self.lines, self.line_ending, self.trailing_newline, self.bom))?;
+            Ok(self.file)
+        }
+
+        /// Confirm and implement the changes like [execute], and return the number of bytes written to the
+        /// file.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let written = file.changer()?
+        ///     .delete_line(4)?
+        ///     .execute_report()?;
+        ///
+        /// // the file is "1,2,3\n4,5,6\n7,8,9" now.
+        /// assert_eq!(written, 17);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be created or written.
+        ///
+        /// [execute]: Changer::execute
+        pub fn execute_report(&self) -> Result<usize, FileError> {
+            let mut content = Vec::new();
+            self.execute_to(&mut content)?;
+            self.write_with(|file| file.write_all(&content))?;
+            Ok(content.len())
+        }
+
+        /// Confirm and implement the changes like [execute], but only if the content is different from
+        /// the file, so that an unchanged file is not written and keeps its modified time. Returns
+        /// whether the file was written.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the value is already 1.
+        /// let written = file.changer()?
+        ///     .change_value(1, 1, "1")?
+        ///     .execute_if_changed()?;
+        ///
+        /// assert!(!written);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be read, created or written.
+        ///
+        /// [execute]: Changer::execute
+        pub fn execute_if_changed(&self) -> Result<bool, FileError> {
+            let mut content = Vec::new();
+            self.execute_to(&mut content)?;
+            match std::fs::read(&self.file.path) {
+                Ok(old) if old == content => return Ok(false),
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+            self.write_with(|file| file.write_all(&content))?;
+            Ok(true)
+        }
+
+        /// Write the changed content to the `writer`, such as the standard output or a [Vec], instead of
+        /// the file. The file is not changed.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // print the changed file.
+        /// file.changer()?
+        ///     .change_value(1, 1, "100")?
+        ///     .execute_to(std::io::stdout())?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the writer fails.
+        pub fn execute_to<W: Write>(&self, mut writer: W) -> Result<(), FileError> {
+            Ok(self.file.write_text(&mut writer, &self.lines, self.line_ending, self.trailing_newline, self.bom)?)
+        }
+    }
+
+    /// A changer class for for writing several values for a new file in succession.
+    ///
+    /// # Example
+    /// collect a [Builder] type (same with [Changer], [Reader]):
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph");
+    /// let mut builder = file.builder();
+    ///
+    /// // write a line:
+    /// // you can consecutive write lines:
+    /// builder.write_line("This is the second line.")
+    ///     .write_line("This is the third line.")
+    ///     .write_line("This is the forth line.")
+    ///     .execute()?; // you will also need to execute your changes:
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    pub struct Builder<'a> {
+        lines: Vec<String>,
+        file: &'a FileAPI,
+        header: Option<String>,
+        append: bool,
+        trailing_newline: bool,
+        bom: bool,
+        header_if_new: bool
+    }
+    impl Builder<'_> {
+        fn from(file: &FileAPI) -> Builder<'_> {
+            Builder {
+                lines: Vec::new(),
+                file,
+                header: None,
+                append: false,
+                trailing_newline: true,
+                bom: false,
+                header_if_new: false
+            }
+        }
+
+        /// Append the lines to the end of the file instead of overwriting it. The file will be created
+        /// if it does not exist. If the last line of the file has no line ending, one is written before
+        /// the new lines. The new lines are ended like the lines of the file, unless the line ending is
+        /// set by [FileAPI::line_ending].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("output.log");
+        ///
+        /// // the existing lines in the file are kept.
+        /// file.builder()
+        ///     .append()
+        ///     .write_line("a new log line.")
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn append(mut self) -> Self {
+            self.append = true;
+            self
+        }
+
+        /// Do not write a newline after the last line. By default every line ends with a newline.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        ///
+        /// // the file will be "1,2\n3,4" without the final newline.
+        /// file.builder()
+        ///     .no_trailing_newline()
+        ///     .write_line("1,2")
+        ///     .write_line("3,4")
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn no_trailing_newline(mut self) -> Self {
+            self.trailing_newline = false;
+            self
+        }
+
+        /// Write a byte order mark at the start of the file, which some tools need to read the file as
+        /// UTF-8, such as the spreadsheets on Windows. In [append] mode, it is only written to an empty
+        /// file. It is not written in the encodings set by `FileAPI::encoding` other than UTF-8 and
+        /// UTF-16, which have no byte order mark.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("export.csv").split(',');
+        ///
+        /// // the file will be "\u{feff}name,price\n".
+        /// file.builder()
+        ///     .write_bom()
+        ///     .write_row(&["name", "price"])
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [append]: Builder::append
+        pub fn write_bom(mut self) -> Self {
+            self.bom = true;
+            self
+        }
+
+        /// Set the names of the columns, which are written as the first line of the file on [execute],
+        /// no matter when this function is called. The names can be found by [Reader::column_index].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("prices.csv").split(',');
+        ///
+        /// // the file will be "name,price\napple,3\n".
+        /// file.builder()
+        ///     .write_row(&["apple", "3"])
+        ///     .set_header(&["name", "price"])
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [execute]: Builder::execute
+        pub fn set_header<T: ToString>(mut self, columns: &[T]) -> Self {
+            let columns = columns.iter().map(|c| c.to_string()).collect::<Vec<String>>();
+            self.header = Some(self.file.join_line(&columns));
+            self
+        }
+
+        /// Append the lines to the file like [append], and set the names of the columns like
+        /// [set_header], but the names are only written if the file is new or empty, such as for a log
+        /// file which has the names once at the top.
+        ///
+        /// Whether the file is new is checked on [execute], so if another process creates the file at
+        /// the same time, both of them may write the names.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("log.csv").split(',');
+        ///
+        /// // the names are written by the first run only.
+        /// file.builder()
+        ///     .ensure_header(&["time", "event"])
+        ///     .write_row(&["12:00", "start"])
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [append]: Builder::append
+        /// [set_header]: Builder::set_header
+        /// [execute]: Builder::execute
+        pub fn ensure_header<T: ToString>(mut self, columns: &[T]) -> Self {
+            self.header_if_new = true;
+            self.append().set_header(columns)
+        }
+
+        /// A function to write a new line in the new file.
+        ///
+        /// # Example
+        /// collect a [Builder] type (same with [Changer], [Reader]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let mut builder = file.builder();
+        ///
+        /// // write a line:
+        /// // you can consecutive write lines:
+        /// builder.write_line("This is the second line.")
+        ///     .write_line("This is the third line.")
+        ///     .write_line("This is the forth line.")
+        ///     .execute()?; // you will also need to execute your changes:
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        pub fn write_line(mut self, line: &str) -> Self {
+            self.lines.push(line.to_string());
+            self
+        }
+
+        /// A function to write a new line with the values divided by the split character.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        ///
+        /// // the file will be "1,2,3\nx,y\n\n".
+        /// file.builder()
+        ///     .write_row(&[1, 2, 3])
+        ///     .write_row(&["x", "y"])
+        ///     .write_row::<usize>(&[]) // an empty line
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// In [csv_mode], a value containing the split character, the quote character or a line break is
+        /// quoted, so that it is read back as one value, and a line with a quoted line break is read
+        /// back as one line. Otherwise the values are written as they are, so turn csv mode on to write
+        /// free text.
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',').csv_mode(true);
+        /// let mut buffer = Vec::new();
+        /// file.builder()
+        ///     .write_row(&["Smith, John", "say \"hi\""])
+        ///     .write_row(&["first\nsecond", "x"])
+        ///     .execute_to(&mut buffer)?;
+        ///
+        /// assert_eq!(buffer, b"\"Smith, John\",\"say \"\"hi\"\"\"\n\"first\nsecond\",x\n");
+        /// let reader = file.reader_from(&buffer[..])?;
+        /// assert_eq!(reader.count_lines(), 2);
+        /// assert_eq!(reader.read_line::<String>(1)?, vec!["Smith, John", "say \"hi\""]);
+        /// assert_eq!(reader.read_line::<String>(2)?, vec!["first\nsecond", "x"]);
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [csv_mode]: FileAPI::csv_mode
+        pub fn write_row<T: ToString>(mut self, values: &[T]) -> Self {
+            let values = values.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+            self.lines.push(self.file.join_line(&values));
+            self
+        }
+
+        /// A function to write many lines at once, each of which is written like [write_row].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        ///
+        /// // the file will be "1,2,3\n4,5,6\n".
+        /// file.builder()
+        ///     .write_rows(&matrix)
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// [write_row]: Builder::write_row
+        pub fn write_rows<T: ToString, R: AsRef<[T]>>(self, rows: &[R]) -> Self {
+            rows.iter().fold(self, |builder, row| builder.write_row(row.as_ref()))
+        }
+
+        /// A function to write a table, whose rows are written like [write_rows]. If `align` is true, the
+        /// values are padded with spaces to the widest value of their column, so that the columns line
+        /// up in a text editor.
+        ///
+        /// The padding is removed when reading the values with trim on, which is the default. Use a split
+        /// character other than the whitespace to align the columns, otherwise the padding is read as
+        /// empty values.
+        ///
+        /// In fixed-width mode set by [FileAPI::fixed_widths], the values are always padded to the widths
+        /// of their columns like in [write_row], and `align` is not used.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("table.txt").split('|');
+        /// let table = vec![vec!["name", "age"], vec!["Alice", "30"], vec!["Bob", "7"]];
+        ///
+        /// // the file will be:
+        /// // name |age
+        /// // Alice|30
+        /// // Bob  |7
+        /// file.builder()
+        ///     .write_table(&table, true)
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// ```
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").fixed_widths(&[5, 5]);
+        /// let builder = file.builder().write_table(&[vec!["a", "b"], vec!["cc", "dd"]], true);
+        ///
+        /// assert_eq!(builder.preview(), "a    b    \ncc   dd   \n");
+        /// ```
+        ///
+        /// [write_rows]: Builder::write_rows
+        /// [write_row]: Builder::write_row
+        pub fn write_table<T: ToString>(mut self, rows: &[Vec<T>], align: bool) -> Self {
+            if self.file.widths.is_some() {
+                return self.write_rows(rows);
+            }
+            let rows = rows.iter()
+                .map(|row| row.iter().map(|v| self.file.quote_value(&v.to_string())).collect::<Vec<String>>())
+                .collect::<Vec<Vec<String>>>();
+            let mut widths: Vec<usize> = Vec::new();
+            if align {
+                for row in &rows {
+                    for (j, value) in row.iter().enumerate() {
+                        let width = value.chars().count();
+                        match widths.get_mut(j) {
+                            Some(w) => *w = (*w).max(width),
+                            None => widths.push(width),
+                        }
+                    }
+                }
+            }
+            for row in rows {
+                let last = row.len().saturating_sub(1);
+                let line = row.iter()
+                    .enumerate()
+                    .map(|(j, value)| match widths.get(j) {
+                        // the last value is not padded, so that the line has no trailing spaces.
+                        Some(&width) if j < last => format!("{:<width$}", value, width = width),
+                        _ => value.to_string(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(&self.file.split);
+                self.lines.push(line);
+            }
+            self
+        }
+
+        /// Write a record, such as a struct, a tuple or a [Vec], as a line whose values are its fields in
+        /// order, like [write_row].
+        ///
+        /// A [None] field is written as an empty value, and a unit variant of an enum is written as its
+        /// name. The fields are quoted in csv mode like [write_row], such as a free text field containing
+        /// the split character.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        /// use serde::Serialize;
+        ///
+        /// #[derive(Serialize)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        ///     email: Option<String>,
+        /// }
+        ///
+        /// let alice = Person { name: "Alice".to_string(), age: 30, email: None };
+        ///
+        /// // the file will be "name,age,email\nAlice,30,".
+        /// let file = FileAPI::from("people.csv").split(',');
+        /// file.builder()
+        ///     .set_header(&["name", "age", "email"])
+        ///     .write_record(&alice)?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Serialize] if a field cannot be written as a single value, such as a
+        /// nested struct or a map.
+        ///
+        /// [write_row]: Builder::write_row
+        #[cfg(feature = "serde")]
+        pub fn write_record<R: Serialize>(self, record: &R) -> Result<Self, FileError> {
+            let mut row = RowSerializer(Vec::new());
+            record.serialize(&mut row)?;
+            Ok(self.write_row(&row.0))
+        }
+
+        /// Write a value as a line of JSON, for the JSON Lines files which are read by
+        /// [Reader::read_jsonl].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        /// use serde::Serialize;
+        ///
+        /// #[derive(Serialize)]
+        /// struct Event {
+        ///     id: u32,
+        ///     kind: String,
+        /// }
+        ///
+        /// // the line will be {"id":3,"kind":"start"}.
+        /// let file = FileAPI::from("events.jsonl");
+        /// file.builder()
+        ///     .append()
+        ///     .write_json(&Event { id: 3, kind: "start".to_string() })?
+        ///     .execute()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Serialize] if the value cannot be serialized into JSON, such as a map
+        /// whose keys are not strings.
+        #[cfg(feature = "serde_json")]
+        pub fn write_json<T: Serialize>(mut self, value: &T) -> Result<Self, FileError> {
+            let line = serde_json::to_string(value).map_err(|e| FileError::Serialize(e.to_string()))?;
+            self.lines.push(line);
+            Ok(self)
+        }
+
+        /// Get the content which [execute] would write, without writing it. In [append] mode, it is the
+        /// content added to the end of the file, so the header of [ensure_header] is only in it if the
+        /// file is empty.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// let builder = file.builder()
+        ///     .set_header(&["a", "b"])
+        ///     .write_row(&[1, 2]);
+        ///
+        /// assert_eq!(builder.preview(), "a,b\n1,2\n");
+        /// ```
+        ///
+        /// [execute]: Builder::execute
+        /// [append]: Builder::append
+        /// [ensure_header]: Builder::ensure_header
+        pub fn preview(&self) -> String {
+            let line_ending = self.file.line_ending.unwrap_or(LineEnding::Lf);
+            // in append mode, the file is checked like in execute, and a missing file is new.
+            let (new, line_break, line_ending) = if self.append {
+                File::open(&self.file.path).and_then(|mut f| self.file.append_state(&mut f)).unwrap_or((true, false, line_ending))
+            } else {
+                (true, false, line_ending)
+            };
+            let empty = String::new();
+            let header = self.header.iter().filter(|_| new || !self.header_if_new);
+            let lines = line_break.then_some(&empty).into_iter().chain(header).chain(&self.lines);
+            let text = join_lines(lines, line_ending, self.trailing_newline);
+            if self.bom && new { format!("{}{}", BOM, text) } else { text }
+        }
+
+        /// Confirm and implement. The lines are written through a buffer, which is flushed and synced to
+        /// the disk before returning, so a failed write is always reported.
+        ///
+        /// # Example
+        /// collect a [Builder] type (same with [Changer], [Reader]):
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// let mut builder = file.builder();
+        ///
+        /// // write a line:
+        /// // you can consecutive write lines:
+        /// builder.write_line("This is the second line.")
+        ///     .write_line("This is the third line.")
+        ///     .write_line("This is the forth line.")
+        ///     .execute()?; // you will also need to execute your changes:
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the file cannot be created or written, such as when the disk is full.
+        pub fn execute(&self) -> Result<&FileAPI, FileError> {
+            let line_ending = self.file.line_ending.unwrap_or(LineEnding::Lf);
+            if self.append {
+                let mut file = OpenOptions::new().read(true).append(true).create(true).open(&self.file.path)?;
+                let (new, line_break, line_ending) = self.file.append_state(&mut file)?;
+                let header = self.header.iter().filter(|_| new || !self.header_if_new);
+                // an empty line is written first to end the last line of the file.
+                let empty = String::new();
+                let lines = line_break.then_some(&empty).into_iter().chain(header).chain(&self.lines);
+                self.file.write_text(&mut file, lines, line_ending, self.trailing_newline, self.bom && new)?;
+                file.sync_all()?;
+            } else {
+                self.file.write_with(|file| self.file.write_text(file, self.header.iter().chain(&self.lines), line_ending, self.trailing_newline, self.bom))?;
+            }
+            Ok(self.file)
+        }
+
+        /// Write the lines to the `writer`, such as the standard output or a [Vec], instead of the file.
+        /// The file is not touched, even in [append] mode.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("").split(',');
+        /// let mut buffer = Vec::new();
+        ///
+        /// file.builder()
+        ///     .write_row(&[1, 2, 3])
+        ///     .execute_to(&mut buffer)?;
+        ///
+        /// assert_eq!(buffer, b"1,2,3\n");
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the writer fails.
+        ///
+        /// [append]: Builder::append
+        pub fn execute_to<W: Write>(&self, mut writer: W) -> Result<(), FileError> {
+            let line_ending = self.file.line_ending.unwrap_or(LineEnding::Lf);
+            Ok(self.file.write_text(&mut writer, self.header.iter().chain(&self.lines), line_ending, self.trailing_newline, self.bom)?)
+        }
+    }
+
+    /// A builder structure writing the lines to the file one by one, created by
+    /// [FileAPI::stream_builder]. Every line ends with a newline.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph").split(',');
+    ///
+    /// // the file will be "1,2,3\n4,5,6\n".
+    /// file.stream_builder()?
+    ///     .write_row(&[1, 2, 3])?
+    ///     .write_line("4,5,6")?
+    ///     .finish()?;
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    pub struct StreamBuilder<'a> {
+        writer: BufWriter<File>,
+        file: &'a FileAPI,
+        line_ending: LineEnding
+    }
+
+    impl<'a> StreamBuilder<'a> {
+        fn from(file: &FileAPI) -> Result<StreamBuilder<'_>, FileError> {
+            Ok(StreamBuilder {
+                writer: BufWriter::new(File::create(&file.path)?),
+                file,
+                line_ending: file.line_ending.unwrap_or(LineEnding::Lf)
+            })
+        }
+
+        /// Write a new line to the file, like [Builder::write_line].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("output.log");
+        /// file.stream_builder()?
+        ///     .write_line("started")?
+        ///     .finish()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the line cannot be written.
+        pub fn write_line(mut self, line: &str) -> Result<Self, FileError> {
+            let text = format!("{}{}", line, self.line_ending.as_str());
+            #[cfg(feature = "encoding_rs")]
+            if let Some(encoding) = self.file.encoding {
+                self.writer.write_all(&encode(encoding, &text))?;
+                return Ok(self);
+            }
+            self.writer.write_all(text.as_bytes())?;
+            Ok(self)
+        }
+
+        /// Write a new line with the values divided by the split character, like [Builder::write_row].
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph").split(',');
+        /// file.stream_builder()?
+        ///     .write_row(&[1, 2, 3])?
+        ///     .finish()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the line cannot be written.
+        pub fn write_row<T: ToString>(self, values: &[T]) -> Result<Self, FileError> {
+            let values = values.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+            let line = self.file.join_line(&values);
+            self.write_line(&line)
+        }
+
+        /// Flush the remaining lines and sync the file to the disk, so that a failed write is always
+        /// reported. If the builder is dropped without it, the errors of the remaining lines are ignored.
+        ///
+        /// # Example
+        /// ```no_run
+        /// use self::simple_file_manager::fileapi::FileAPI;
+        ///
+        /// let file = FileAPI::from("filename.gph");
+        /// file.stream_builder()?
+        ///     .write_line("1 2 3")?
+        ///     .finish()?;
+        /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [FileError::Io] if the lines cannot be flushed or synced, such as when the disk is
+        /// full.
+        pub fn finish(self) -> Result<&'a FileAPI, FileError> {
+            let file = self.writer.into_inner().map_err(|e| e.into_error())?;
+            file.sync_all()?;
+            Ok(self.file)
+        }
+    }
+
+    /// An iterator reading a file line by line and dividing every line by the split character.
+    ///
+    /// Each item is a [Result], as reading a line from the file may fail.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph").split(',');
+    /// let rows = file.row_iter()?.collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(rows[0], vec!["1", "2", "3"]);
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    pub struct RowIter<'a> {
+        reader: BufReader<File>,
+        file: &'a FileAPI,
+        // the number of the first line of the last record read, counting the skipped lines. A record is
+        // more than one line of the file if it has a quoted line break.
+        line: usize,
+        // the number of the lines of the file read so far.
+        lines_read: usize,
+        // whether the lines are ended by a single '\r'.
+        cr: bool,
+        // the offset of the next line in the file, for the errors of decoding it.
+        offset: usize
+    }
+
+    impl RowIter<'_> {
+        fn from(file: &FileAPI) -> Result<RowIter<'_>, FileError> {
+            file.require_utf8()?;
+            let cr = ends_lines_by_cr(BufReader::new(File::open(&file.path)?))?;
+            let mut reader = BufReader::new(File::open(&file.path)?);
+            let offset = skip_bom(&mut reader)?;
+            Ok(RowIter { reader, file, line: 0, lines_read: 0, cr, offset })
+        }
+
+        // read the next line without the line ending.
+        fn next_line(&mut self) -> Option<Result<String, FileError>> {
+            let mut buffer = Vec::new();
+            let read = match read_record(&mut self.reader, &mut buffer, self.file.line_quote(), self.cr) {
+                Ok(0) => return None,
+                Ok(read) => read,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line = self.lines_read + 1;
+            self.lines_read += line_bounds(&buffer, None, self.cr).len();
+            strip_line_ending(&mut buffer, self.cr);
+            let offset = self.offset;
+            self.offset += read;
+            Some(String::from_utf8(buffer).map_err(|e| FileError::InvalidEncoding { byte_offset: offset + e.utf8_error().valid_up_to() }))
+        }
+    }
+
+    impl Iterator for RowIter<'_> {
+        type Item = Result<Vec<String>, FileError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let line = loop {
+                match self.next_line()? {
+                    Ok(l) if self.file.is_skipped(&l) => continue,
+                    line => break line,
+                }
+            };
+            Some(line.map(|l| {
+                self.file.split_line(&l)
+            }))
+        }
+    }
+
+    /// An iterator reading a file line by line and parsing every line into a certain type.
+    ///
+    /// A line with a value which cannot be parsed gives a [FileError::Parse] item, and the iteration
+    /// can go on with the next line.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::FileAPI;
+    ///
+    /// let file = FileAPI::from("filename.gph").split(',');
+    ///
+    /// for row in file.parsed_iter::<usize>()? {
+    ///     match row {
+    ///         Ok(row) => println!("{:?}", row),
+    ///         Err(e) => eprintln!("skip a bad line: {}", e),
+    ///     }
+    /// }
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    pub struct ParsedIter<'a, T> {
+        rows: RowIter<'a>,
+        _type: PhantomData<T>
+    }
+
+    impl<T: FromStr> Iterator for ParsedIter<'_, T>
+        where
+            <T as FromStr>::Err: Debug,
+    {
+        type Item = Result<Vec<T>, FileError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let row = self.rows.next()?;
+            Some(row.and_then(|r| self.rows.file.parse_values(self.rows.line, &r)))
+        }
+    }
+
+    /// The characters ending a line in the file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::{FileAPI, LineEnding};
+    ///
+    /// let file = FileAPI::from("filename.gph").line_ending(LineEnding::CrLf);
+    /// file.builder()
+    ///     .write_line("1,2,3")
+    ///     .execute()?;
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum LineEnding {
+        /// `\n`, used by Unix-like systems.
+        Lf,
+        /// `\r\n`, used by Windows.
+        CrLf,
+        /// `\r`, used by classic Mac OS.
+        Cr,
+    }
+
+    impl LineEnding {
+        /// Get the characters of the line ending.
+        ///
+        /// # Example
+        /// ```
+        /// use self::simple_file_manager::fileapi::LineEnding;
+        ///
+        /// assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+        /// ```
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                LineEnding::Lf => "\n",
+                LineEnding::CrLf => "\r\n",
+                LineEnding::Cr => "\r",
+            }
+        }
+
+        // find the line ending of the first line, or Lf if there is only one line.
+        fn detect(text: &str) -> LineEnding {
+            match text.find(['\r', '\n']) {
+                Some(i) if text[i..].starts_with("\r\n") => LineEnding::CrLf,
+                Some(i) if text[i..].starts_with('\r') => LineEnding::Cr,
+                _ => LineEnding::Lf,
+            }
+        }
+    }
+
+    /// A difference of a line between two files, found by [FileAPI::diff].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::{DiffKind, FileAPI};
+    ///
+    /// for (line, kind) in FileAPI::from("old.csv").diff(&FileAPI::from("new.csv"))? {
+    ///     match kind {
+    ///         DiffKind::Added(l) => println!("{}: + {}", line, l),
+    ///         DiffKind::Removed(l) => println!("{}: - {}", line, l),
+    ///         DiffKind::Changed { old, new } => println!("{}: {} -> {}", line, old, new),
+    ///     }
+    /// }
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DiffKind {
+        /// The line is only in the other file.
+        Added(String),
+        /// The line is only in this file.
+        Removed(String),
+        /// The line is different in the two files.
+        Changed { old: String, new: String },
+    }
+
+    /// The handling of the empty values when a whole line is parsed, set by
+    /// [FileAPI::empty_field_policy].
+    ///
+    /// # Example
+    /// ```
+    /// use self::simple_file_manager::fileapi::{EmptyFieldPolicy, FileAPI, FileError};
+    ///
+    /// let file = FileAPI::from("").split(',').empty_field_policy(EmptyFieldPolicy::Error);
+    /// let reader = file.reader_from("1,,3".as_bytes())?;
+    ///
+    /// assert!(matches!(reader.read_line::<usize>(1), Err(FileError::EmptyValue { line: 1, row: 2 })));
+    /// # Ok::<(), simple_file_manager::fileapi::FileError>(())
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum EmptyFieldPolicy {
+        /// Parse the empty values like the others.
+        Keep,
+        /// Give a [FileError::EmptyValue] for an empty value.
+        Error,
+        /// Leave the empty values out of the line.
+        SkipParse,
+    }
+
+    /// The error type of all the fallible operations in this module.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use self::simple_file_manager::fileapi::{FileAPI, FileError};
+    ///
+    /// match FileAPI::from("filename.gph").reader() {
+    ///     Ok(reader) => println!("{} lines", reader.count_lines()),
+    ///     Err(FileError::Io(e)) => eprintln!("cannot open the file: {}", e),
+    ///     Err(e) => eprintln!("{}", e),
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub enum FileError {
+        /// An error from the underlying file system.
+        Io(io::Error),
+        /// A value cannot be parsed into the requested type. `line` and `row` are the position of the
+        /// value, where `row` is 0 when it is about the whole line, and `line` is 0 when the position
+        /// is not known, such as for the values selected by [Reader::read_value]. `value` is the text
+        /// which cannot be parsed, which is empty when it is not known, and `message` tells why.
+        Parse { line: usize, row: usize, value: String, message: String },
+        /// There is no value at this position. `row` is 0 when the whole line is missing.
+        IndexOutOfBounds { line: usize, row: usize },
+        /// The file has no lines.
+        EmptyFile,
+        /// A line has a different number of values than expected.
+        RaggedRow { line: usize, expected: usize, found: usize },
+        /// There is no column with this name in the header.
+        UnknownColumn(String),
+        /// The file cannot be decoded in its encoding. `byte_offset` is the position of the first byte
+        /// which cannot be decoded, counted from the start of the file or the source, including the byte
+        /// order mark if there is one.
+        InvalidEncoding { byte_offset: usize },
+        /// A record cannot be written as a line.
+        Serialize(String),
+        /// The pattern is not a valid regular expression.
+        InvalidPattern(String),
+        /// There is an empty value at this position, with [EmptyFieldPolicy::Error].
+        EmptyValue { line: usize, row: usize },
+    }
+
+    impl Display for FileError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self {
+                FileError::Io(e) => write!(f, "IO error: {}", e),
+                FileError::Parse { line, row, value, message } => {
+                    match (line, row) {
+                        (0, _) => write!(f, "parse error: ")?,
+                        (line, 0) => write!(f, "parse error at line {}: ", line)?,
+                        (line, row) => write!(f, "parse error at line {}, row {}: ", line, row)?,
+                    }
+                    if value.is_empty() { write!(f, "{}", message) } else { write!(f, "{:?} ({})", value, message) }
+                }
+                FileError::IndexOutOfBounds { line, row } => write!(f, "index out of bounds: line {}, row {}", line, row),
+                FileError::EmptyFile => write!(f, "the file is empty"),
+                FileError::RaggedRow { line, expected, found } => write!(f, "ragged row: line {} has {} values, expected {}", line, found, expected),
+                FileError::UnknownColumn(name) => write!(f, "unknown column: {:?}", name),
+                FileError::InvalidEncoding { byte_offset } => write!(f, "the file cannot be decoded in its encoding at byte {}", byte_offset),
+                FileError::Serialize(msg) => write!(f, "serialize error: {}", msg),
+                FileError::InvalidPattern(msg) => write!(f, "invalid pattern: {}", msg),
+                FileError::EmptyValue { line, row } => write!(f, "empty value at line {}, row {}", line, row),
+            }
+        }
+    }
+
+    impl FileError {
+        // give the position to a parse error which does not have one.
+        fn at(self, line: usize, row: usize) -> FileError {
+            match self {
+                FileError::Parse { line: 0, value, message, .. } => FileError::Parse { line, row, value, message },
+                e => e,
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl de::Error for FileError {
+        fn custom<T: Display>(msg: T) -> Self {
+            FileError::Parse { line: 0, row: 0, value: String::new(), message: msg.to_string() }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl ser::Error for FileError {
+        fn custom<T: Display>(msg: T) -> Self {
+            FileError::Serialize(msg.to_string())
+        }
+    }
+
+    impl Error for FileError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                FileError::Io(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<io::Error> for FileError {
+        fn from(e: io::Error) -> Self {
+            FileError::Io(e)
+        }
+    }
+
+    // parse a single value into certain type.
+    fn parse_value<T: FromStr>(value: &str) -> Result<T, FileError>
+        where
+            <T as FromStr>::Err: Debug,
+    {
+        value.parse::<T>().map_err(|e| FileError::Parse { line: 0, row: 0, value: value.to_string(), message: format!("{:?}", e) })
+    }
+
+    // flip the lines and rows of a rectangular grid.
+    fn transpose<T>(grid: Vec<Vec<T>>) -> Vec<Vec<T>> {
+        let columns = grid.first().map_or(0, |l| l.len());
+        let mut transposed: Vec<Vec<T>> = (0..columns).map(|_| Vec::with_capacity(grid.len())).collect();
+        for line in grid {
+            for (j, value) in line.into_iter().enumerate() {
+                transposed[j].push(value);
+            }
+        }
+        transposed
+    }
+
+    // find the line ending of the text. If it is a single '\r', the line endings are converted to '\n'
+    // so that the lines can be divided like the others, where a '\r\n' is one line ending too.
+    fn normalize_cr(text: String) -> (String, LineEnding) {
+        let line_ending = LineEnding::detect(&text);
+        if line_ending == LineEnding::Cr {
+            return (text.replace("\r\n", "\n").replace('\r', "\n"), line_ending);
+        }
+        (text, line_ending)
+    }
+
+    // find the start and end of every line in the text, without the line ending. The lines are the
+    // same as the ones given by str::lines, except that a line break between the `quote` characters
+    // does not end the line, and a single '\r' ends the line too if `cr` is true.
+    fn line_bounds(bytes: &[u8], quote: Option<char>, cr: bool) -> Vec<(usize, usize)> {
+        let mut buffer = [0; 4];
+        let quote = quote.map(|q| q.encode_utf8(&mut buffer).as_bytes());
+        let mut bounds = Vec::new();
+        let mut start = 0;
+        let mut quoted = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            if let Some(q) = quote.filter(|q| bytes[i..].starts_with(q)) {
+                quoted = !quoted;
+                i += q.len();
+                continue;
+            }
+            if !quoted && (bytes[i] == b'\n' || (cr && bytes[i] == b'\r')) {
+                let crlf = bytes[i] == b'\r' && bytes.get(i + 1) == Some(&b'\n');
+                let end = if bytes[i] == b'\n' && i > start && bytes[i - 1] == b'\r' { i - 1 } else { i };
+                bounds.push((start, end));
+                i += if crlf { 2 } else { 1 };
+                start = i;
+                continue;
+            }
+            i += 1;
+        }
+        if start < bytes.len() {
+            bounds.push((start, bytes.len()));
+        }
+        bounds
+    }
+
+    // read a line into the buffer with its line ending like [read_line_bytes], going on to the next
+    // line while the line break is between the `quote` characters. It returns the number of bytes read.
+    fn read_record<R: BufRead>(reader: &mut R, buffer: &mut Vec<u8>, quote: Option<char>, cr: bool) -> io::Result<usize> {
+        let mut counted = buffer.len();
+        let mut read = read_line_bytes(reader, buffer, cr)?;
+        if let Some(q) = quote {
+            let mut q_buffer = [0; 4];
+            let q = q.encode_utf8(&mut q_buffer).as_bytes();
+            let mut quotes = 0;
+            loop {
+                quotes += buffer[counted..].windows(q.len()).filter(|w| *w == q).count();
+                counted = buffer.len();
+                if quotes % 2 == 0 {
+                    break;
+                }
+                match read_line_bytes(reader, buffer, cr)? {
+                    0 => break,
+                    n => read += n,
+                }
+            }
+        }
+        Ok(read)
+    }
+
+    // read a line into the buffer with its line ending like BufRead::read_until, where a single '\r'
+    // ends the line too if `cr` is true. It returns the number of bytes read.
+    fn read_line_bytes<R: BufRead>(reader: &mut R, buffer: &mut Vec<u8>, cr: bool) -> io::Result<usize> {
+        if !cr {
+            return reader.read_until(b'\n', buffer);
+        }
+        let mut read = 0;
+        loop {
+            let available = reader.fill_buf()?;
+            let Some(i) = available.iter().position(|&b| b == b'\r' || b == b'\n') else {
+                let len = available.len();
+                if len == 0 {
+                    return Ok(read);
+                }
+                buffer.extend_from_slice(available);
+                reader.consume(len);
+                read += len;
+                continue;
+            };
+            let end = available[i];
+            buffer.extend_from_slice(&available[..=i]);
+            reader.consume(i + 1);
+            read += i + 1;
+            // the '\n' of a '\r\n' may be in the next part of the reader.
+            if end == b'\r' && reader.fill_buf()?.first() == Some(&b'\n') {
+                buffer.push(b'\n');
+                reader.consume(1);
+                read += 1;
+            }
+            return Ok(read);
+        }
+    }
+
+    // remove the line ending at the end of a line read by [read_line_bytes].
+    fn strip_line_ending(buffer: &mut Vec<u8>, cr: bool) {
+        if buffer.ends_with(b"\n") {
+            buffer.pop();
+            if buffer.ends_with(b"\r") {
+                buffer.pop();
+            }
+        } else if cr && buffer.ends_with(b"\r") {
+            buffer.pop();
+        }
+    }
+
+    // check if the lines are ended by a single '\r' like [LineEnding::detect], reading only the first
+    // line of the reader.
+    fn ends_lines_by_cr<R: BufRead>(mut reader: R) -> io::Result<bool> {
+        loop {
+            let available = reader.fill_buf()?;
+            if available.is_empty() {
+                return Ok(false);
+            }
+            if let Some(i) = available.iter().position(|&b| b == b'\r' || b == b'\n') {
+                if available[i] == b'\n' {
+                    return Ok(false);
+                }
+                reader.consume(i + 1);
+                return Ok(reader.fill_buf()?.first() != Some(&b'\n'));
+            }
+            let len = available.len();
+            reader.consume(len);
+        }
+    }
+
+    // join the lines by the line ending, and add a line ending after the last line if needed.
+    fn join_lines<'a, I: IntoIterator<Item = &'a String>>(lines: I, line_ending: LineEnding, trailing_newline: bool) -> String {
+        let lines = lines.into_iter().map(String::as_str).collect::<Vec<&str>>();
+        let mut text = lines.join(line_ending.as_str());
+        if trailing_newline && !lines.is_empty() {
+            text.push_str(line_ending.as_str());
+        }
+        text
+    }
+
+    // remove the byte order mark at the start of the text, and return whether there was one.
+    fn strip_bom(text: &mut String) -> bool {
+        let bom = text.starts_with(BOM);
+        if bom {
+            text.drain(..BOM.len_utf8());
+        }
+        bom
+    }
+
+    // skip the byte order mark at the start of a reader, and return the number of bytes skipped.
+    fn skip_bom<R: BufRead>(reader: &mut R) -> io::Result<usize> {
+        if reader.fill_buf()?.starts_with("\u{feff}".as_bytes()) {
+            reader.consume(BOM.len_utf8());
+            return Ok(BOM.len_utf8());
+        }
+        Ok(0)
+    }
+
+    // write the lines divided by the line ending, and a line ending after the last line if needed.
+    fn write_lines<'a, W: Write, I: IntoIterator<Item = &'a String>>(writer: &mut W, lines: I, line_ending: LineEnding, trailing_newline: bool) -> io::Result<()> {
+        let mut lines = lines.into_iter().peekable();
+        while let Some(line) = lines.next() {
+            writer.write_all(line.as_bytes())?;
+            if lines.peek().is_some() || trailing_newline {
+                writer.write_all(line_ending.as_str().as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    // find the offset of the first byte which cannot be decoded in the encoding. The byte order mark
+    // is decoded like the other bytes as in [FileAPI::decode], so it is counted in the offset.
+    #[cfg(feature = "encoding_rs")]
+    fn malformed_offset(encoding: &'static Encoding, bytes: &[u8]) -> usize {
+        let mut decoder = encoding.new_decoder_without_bom_handling();
+        let mut text = String::with_capacity(decoder.max_utf8_buffer_length(bytes.len()).unwrap_or(bytes.len()));
+        let mut offset = 0;
+        loop {
+            let (result, read) = decoder.decode_to_string_without_replacement(&bytes[offset..], &mut text, true);
+            offset += read;
+            match result {
+                // the malformed bytes and the bytes read after them are counted in `read`.
+                encoding_rs::DecoderResult::Malformed(bad, after) => return offset - bad as usize - after as usize,
+                encoding_rs::DecoderResult::OutputFull => text.reserve(bytes.len()),
+                encoding_rs::DecoderResult::InputEmpty => return offset,
+            }
+        }
+    }
+
+    // encode the text. encoding_rs only encodes UTF-16 into UTF-8, so it is done here.
+    #[cfg(feature = "encoding_rs")]
+    fn encode(encoding: &'static Encoding, text: &str) -> Vec<u8> {
+        if encoding == encoding_rs::UTF_16LE {
+            text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+        } else if encoding == encoding_rs::UTF_16BE {
+            text.encode_utf16().flat_map(u16::to_be_bytes).collect()
+        } else {
+            encoding.encode(text).0.into_owned()
+        }
+    }
+
+    // the settings of a deserialized [FileAPI], which are checked like in the functions setting them
+    // before the [FileAPI] is made.
+    #[cfg(feature = "serde")]
+    #[derive(serde::Deserialize)]
+    struct FileAPISettings {
+        path: String,
+        split: String,
+        csv_mode: bool,
+        trim: bool,
+        atomic: bool,
+        line_ending: Option<LineEnding>,
+        comment: Option<char>,
+        skip_blank_lines: bool,
+        empty_fields: EmptyFieldPolicy,
+        quote: char,
+        widths: Option<Vec<usize>>,
+        #[cfg(feature = "encoding_rs")]
+        encoding: Option<&'static Encoding>
+    }
+
+    #[cfg(feature = "serde")]
+    impl TryFrom<FileAPISettings> for FileAPI {
+        type Error = String;
+
+        fn try_from(settings: FileAPISettings) -> Result<Self, Self::Error> {
+            if settings.split.is_empty() {
+                return Err("the split string should not be empty".to_string());
+            }
+            if settings.csv_mode && settings.split.contains(settings.quote) {
+                return Err("the split string should not contain the quote character in csv mode".to_string());
+            }
+            if settings.widths.as_ref().is_some_and(|w| w.is_empty() || w.contains(&0)) {
+                return Err("the widths should not be empty or contain 0".to_string());
+            }
+            Ok(FileAPI {
+                path: settings.path,
+                split: settings.split,
+                csv_mode: settings.csv_mode,
+                trim: settings.trim,
+                atomic: settings.atomic,
+                line_ending: settings.line_ending,
+                comment: settings.comment,
+                skip_blank_lines: settings.skip_blank_lines,
+                empty_fields: settings.empty_fields,
+                quote: settings.quote,
+                widths: settings.widths,
+                #[cfg(feature = "encoding_rs")]
+                encoding: settings.encoding
+            })
+        }
+    }
+
+    // a deserializer giving the values of a line to the fields of a record in order.
+    #[cfg(feature = "serde")]
+    struct RowDeserializer {
+        line: usize,
+        // the row of the last value given to a field.
+        row: usize,
+        values: std::vec::IntoIter<String>,
+    }
+
+    #[cfg(feature = "serde")]
+    impl RowDeserializer {
+        fn new(file: &FileAPI, line: usize, text: &str) -> RowDeserializer {
+            let values = file.split_line(text)
+                .iter()
+                .map(|v| file.trimmed(v).to_string())
+                .collect::<Vec<String>>();
+            RowDeserializer { line, row: 0, values: values.into_iter() }
+        }
+
+        // make sure that the line has as many values as the record has fields.
+        fn check_len(&self, expected: usize) -> Result<(), FileError> {
+            let found = self.values.len();
+            if found != expected {
+                return Err(FileError::RaggedRow { line: self.line, expected, found });
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> Deserializer<'de> for RowDeserializer {
+        type Error = FileError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FileError> {
+            visitor.visit_seq(self)
+        }
+
+        fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, FileError> {
+            self.check_len(len)?;
+            visitor.visit_seq(self)
+        }
+
+        fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, len: usize, visitor: V) -> Result<V::Value, FileError> {
+            self.deserialize_tuple(len, visitor)
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, FileError> {
+            self.deserialize_tuple(fields.len(), visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+            unit unit_struct newtype_struct seq map enum identifier ignored_any
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> SeqAccess<'de> for RowDeserializer {
+        type Error = FileError;
+
+        fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, FileError> {
+            self.row += 1;
+            let (line, row) = (self.line, self.row);
+            self.values.next().map(|v| seed.deserialize(CellDeserializer(v)).map_err(|e| e.at(line, row))).transpose()
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.values.len())
+        }
+    }
+
+    // a deserializer parsing a single value into the type of a field.
+    #[cfg(feature = "serde")]
+    struct CellDeserializer(String);
+
+    // deserialize the value by parsing it with FromStr.
+    #[cfg(feature = "serde")]
+    macro_rules! deserialize_parsed {
+        ($($method:ident => $visit:ident,)*) => {
+            $(
+                fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FileError> {
+                    visitor.$visit(parse_value(&self.0)?)
+                }
+            )*
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> Deserializer<'de> for CellDeserializer {
+        type Error = FileError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FileError> {
+            visitor.visit_string(self.0)
+        }
+
+        deserialize_parsed! {
+            deserialize_bool => visit_bool,
+            deserialize_i8 => visit_i8,
+            deserialize_i16 => visit_i16,
+            deserialize_i32 => visit_i32,
+            deserialize_i64 => visit_i64,
+            deserialize_i128 => visit_i128,
+            deserialize_u8 => visit_u8,
+            deserialize_u16 => visit_u16,
+            deserialize_u32 => visit_u32,
+            deserialize_u64 => visit_u64,
+            deserialize_u128 => visit_u128,
+            deserialize_f32 => visit_f32,
+            deserialize_f64 => visit_f64,
+            deserialize_char => visit_char,
+        }
+
+        fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FileError> {
+            if self.0.is_empty() {
+                visitor.visit_none()
+            } else {
+                visitor.visit_some(self)
+            }
+        }
+
+        fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, FileError> {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, FileError> {
+            visitor.visit_enum(self.0.into_deserializer())
+        }
+
+        serde::forward_to_deserialize_any! {
+            str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+        }
+    }
+
+    // a serializer collecting the fields of a record as the values of a line.
+    #[cfg(feature = "serde")]
+    struct RowSerializer(Vec<String>);
+
+    #[cfg(feature = "serde")]
+    impl RowSerializer {
+        fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FileError> {
+            self.0.push(value.serialize(CellSerializer)?);
+            Ok(())
+        }
+    }
+
+    // serialize a value which is a single field of the record.
+    #[cfg(feature = "serde")]
+    macro_rules! serialize_field {
+        ($($method:ident: $ty:ty,)*) => {
+            $(
+                fn $method(self, v: $ty) -> Result<(), FileError> {
+                    self.push(&v)
+                }
+            )*
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    impl Serializer for &mut RowSerializer {
+        type Ok = ();
+        type Error = FileError;
+        type SerializeSeq = Self;
+        type SerializeTuple = Self;
+        type SerializeTupleStruct = Self;
+        type SerializeTupleVariant = Impossible<(), FileError>;
+        type SerializeMap = Impossible<(), FileError>;
+        type SerializeStruct = Self;
+        type SerializeStructVariant = Impossible<(), FileError>;
+
+        serialize_field! {
+            serialize_bool: bool,
+            serialize_i8: i8,
+            serialize_i16: i16,
+            serialize_i32: i32,
+            serialize_i64: i64,
+            serialize_i128: i128,
+            serialize_u8: u8,
+            serialize_u16: u16,
+            serialize_u32: u32,
+            serialize_u64: u64,
+            serialize_u128: u128,
+            serialize_f32: f32,
+            serialize_f64: f64,
+            serialize_char: char,
+            serialize_str: &str,
+            serialize_bytes: &[u8],
+        }
+
+        fn serialize_none(self) -> Result<(), FileError> {
+            self.push(&())
+        }
+
+        fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), FileError> {
+            value.serialize(self)
+        }
+
+        fn serialize_unit(self) -> Result<(), FileError> {
+            self.push(&())
+        }
+
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), FileError> {
+            self.push(&())
+        }
+
+        fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<(), FileError> {
+            self.push(variant)
+        }
+
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), FileError> {
+            value.serialize(self)
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(self, name: &'static str, _index: u32, variant: &'static str, _value: &T) -> Result<(), FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has a value", name, variant)))
+        }
+
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self, FileError> {
+            Ok(self)
+        }
+
+        fn serialize_tuple(self, _len: usize) -> Result<Self, FileError> {
+            Ok(self)
+        }
+
+        fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, FileError> {
+            Ok(self)
+        }
+
+        fn serialize_tuple_variant(self, name: &'static str, _index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has values", name, variant)))
+        }
+
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, FileError> {
+            Err(FileError::Serialize("a map cannot be written as a line".to_string()))
+        }
+
+        fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, FileError> {
+            Ok(self)
+        }
+
+        fn serialize_struct_variant(self, name: &'static str, _index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has values", name, variant)))
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl SerializeSeq for &mut RowSerializer {
+        type Ok = ();
+        type Error = FileError;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FileError> {
+            self.push(value)
+        }
+
+        fn end(self) -> Result<(), FileError> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl SerializeTuple for &mut RowSerializer {
+        type Ok = ();
+        type Error = FileError;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FileError> {
+            self.push(value)
+        }
+
+        fn end(self) -> Result<(), FileError> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl SerializeTupleStruct for &mut RowSerializer {
+        type Ok = ();
+        type Error = FileError;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FileError> {
+            self.push(value)
+        }
+
+        fn end(self) -> Result<(), FileError> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl SerializeStruct for &mut RowSerializer {
+        type Ok = ();
+        type Error = FileError;
+
+        fn serialize_field<T: Serialize + ?Sized>(&mut self, _key: &'static str, value: &T) -> Result<(), FileError> {
+            self.push(value)
+        }
+
+        fn end(self) -> Result<(), FileError> {
+            Ok(())
+        }
+    }
+
+    // a serializer writing a single field of a record as a value.
+    #[cfg(feature = "serde")]
+    struct CellSerializer;
+
+    // serialize a value by its Display implementation.
+    #[cfg(feature = "serde")]
+    macro_rules! serialize_display {
+        ($($method:ident: $ty:ty,)*) => {
+            $(
+                fn $method(self, v: $ty) -> Result<String, FileError> {
+                    Ok(v.to_string())
+                }
+            )*
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    impl Serializer for CellSerializer {
+        type Ok = String;
+        type Error = FileError;
+        type SerializeSeq = Impossible<String, FileError>;
+        type SerializeTuple = Impossible<String, FileError>;
+        type SerializeTupleStruct = Impossible<String, FileError>;
+        type SerializeTupleVariant = Impossible<String, FileError>;
+        type SerializeMap = Impossible<String, FileError>;
+        type SerializeStruct = Impossible<String, FileError>;
+        type SerializeStructVariant = Impossible<String, FileError>;
+
+        serialize_display! {
+            serialize_bool: bool,
+            serialize_i8: i8,
+            serialize_i16: i16,
+            serialize_i32: i32,
+            serialize_i64: i64,
+            serialize_i128: i128,
+            serialize_u8: u8,
+            serialize_u16: u16,
+            serialize_u32: u32,
+            serialize_u64: u64,
+            serialize_u128: u128,
+            serialize_f32: f32,
+            serialize_f64: f64,
+            serialize_char: char,
+            serialize_str: &str,
+        }
+
+        fn serialize_bytes(self, v: &[u8]) -> Result<String, FileError> {
+            String::from_utf8(v.to_vec()).map_err(|_| FileError::Serialize("the bytes are not UTF-8".to_string()))
+        }
+
+        fn serialize_none(self) -> Result<String, FileError> {
+            Ok(String::new())
+        }
+
+        fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, FileError> {
+            value.serialize(self)
+        }
+
+        fn serialize_unit(self) -> Result<String, FileError> {
+            Ok(String::new())
+        }
+
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<String, FileError> {
+            Ok(String::new())
+        }
+
+        fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<String, FileError> {
+            Ok(variant.to_string())
+        }
+
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<String, FileError> {
+            value.serialize(self)
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(self, name: &'static str, _index: u32, variant: &'static str, _value: &T) -> Result<String, FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has a value", name, variant)))
+        }
+
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, FileError> {
+            Err(FileError::Serialize("a nested sequence cannot be written as a value".to_string()))
+        }
+
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, FileError> {
+            Err(FileError::Serialize("a nested tuple cannot be written as a value".to_string()))
+        }
+
+        fn serialize_tuple_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, FileError> {
+            Err(FileError::Serialize(format!("the nested struct {} cannot be written as a value", name)))
+        }
+
+        fn serialize_tuple_variant(self, name: &'static str, _index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has values", name, variant)))
+        }
+
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, FileError> {
+            Err(FileError::Serialize("a map cannot be written as a value".to_string()))
+        }
+
+        fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct, FileError> {
+            Err(FileError::Serialize(format!("the nested struct {} cannot be written as a value", name)))
+        }
+
+        fn serialize_struct_variant(self, name: &'static str, _index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, FileError> {
+            Err(FileError::Serialize(format!("the variant {}::{} has values", name, variant)))
         }
     }
 }